@@ -0,0 +1,141 @@
+use std::process::Command;
+
+use parsing_error::ParsingError;
+use parsing_config::ArgDesc;
+use parsing_config::ParsingConfig;
+use parsing_config::SingleHyphenMode;
+use parsed_args::ParsedArgs;
+use parse::parses_as_unnamed;
+
+
+/// Returns the arguments that set a flag, or that set an optional parameter to its implicit value, or `None` if no enabled syntax can specify it.
+fn flag_args(config: &ParsingConfig, name: &str) -> Option<Vec<String>> {
+    let short = name.chars().count() == 1;
+    if name.is_empty() {
+        // With any other syntax, the naked hyphen option would be read as the double hyphen marker or not as an option at all.
+        if config.sh_syntax {
+            Some(vec![String::from("-")])
+        } else if config.dh_syntax && !config.dh_marker {
+            Some(vec![String::from("--")])
+        } else {
+            None
+        }
+    } else if config.dh_syntax {
+        Some(vec![format!("--{}", name)])
+    } else if config.sh_syntax && (short || config.sh_mode != SingleHyphenMode::Clustered) {
+        Some(vec![format!("-{}", name)])
+    } else if config.slash_syntax {
+        Some(vec![format!("/{}", name)])
+    } else {
+        None
+    }
+}
+
+/// Returns the arguments that assign the `value` to a parameter, or `None` if no enabled syntax can specify it.
+fn param_args(config: &ParsingConfig, name: &str, value: &str, optional: bool) -> Option<Vec<String>> {
+    let short = name.chars().count() == 1;
+    if name.is_empty() {
+        // A value is only attached to a single hyphen if it cannot be read as a cluster of options.
+        if config.dha_syntax {
+            Some(vec![format!("--={}", value)])
+        } else if config.sh_syntax && value.chars().next().is_some_and(|x| !x.is_alphabetic()) {
+            Some(vec![format!("-{}", value)])
+        } else {
+            None
+        }
+    } else if config.dha_syntax {
+        Some(vec![format!("--{}={}", name, value)])
+    } else if config.dh_syntax && !optional {
+        Some(vec![format!("--{}", name), value.to_string()])
+    } else if config.slash_syntax {
+        Some(vec![format!("/{}{}{}", name, config.slash_separator, value)])
+    } else if config.a_syntax {
+        Some(vec![format!("{}={}", name, value)])
+    } else if config.sh_syntax && !optional && (short || config.sh_mode != SingleHyphenMode::Clustered) {
+        Some(vec![format!("-{}", name), value.to_string()])
+    } else {
+        None
+    }
+}
+
+
+impl ParsedArgs {
+    /// Returns an argument list that produces the same flags, parameters, unnamed parameters and subcommand when it is parsed with the `config`.
+    /// The first argument is the name of the command.
+    ///
+    /// Options are specified by their full names, in alphabetical order, followed by the unknown arguments, the unnamed parameters and the subcommand.
+    /// The unknown arguments are the ones returned by `unknown_args`, in their original order.
+    /// The syntax for each option is the first of the enabled syntaxes that can specify it.
+    /// Optional parameters that were set to their implicit value are specified without a value if possible.
+    /// If an unnamed parameter would not be interpreted as an unnamed parameter at its position, the unnamed parameters are preceded by the double hyphen marker.
+    ///
+    /// Returns an error if the results cannot be reproduced with the `config`:
+    /// `UnrepresentableOption` for options that no enabled syntax can specify, including flags that were turned off without plus syntax and a name of one character,
+    /// for unknown arguments when unknown option passthrough is disabled, and for a passthrough parameter that was the last argument and has no value,
+    /// and `UnrepresentableArgument` for unnamed parameters that would be mistaken for an option or a subcommand, when the double hyphen marker is disabled or stored, or when there is a subcommand.
+    pub fn to_args(&self, config: &ParsingConfig) -> Result<Vec<String>, ParsingError> {
+        let mut args = vec![self.name.clone()];
+
+        let mut names: Vec<&String> = self.flags.iter().chain(self.off_flags.iter()).chain(self.params.keys()).collect();
+        names.sort_unstable();
+        for name in names {
+            let option_args = if self.flags.contains(name) {
+                flag_args(config, name)
+            } else if let Some(value) = self.params.get(name) {
+                let optional = matches!(config.options.get(name), Some(ArgDesc::OptionalParam(_)));
+                let implicit_args = if self.used_implicit(name) { flag_args(config, name) } else { None };
+                implicit_args.or_else(|| param_args(config, name, value, optional))
+            } else if config.plus_syntax && name.chars().count() == 1 {
+                Some(vec![format!("+{}", name)])
+            } else {
+                None
+            };
+            match option_args {
+                Some(option_args) => args.extend(option_args),
+                None => return Err(ParsingError::UnrepresentableOption(name.to_string())),
+            }
+        }
+
+        // The value of a passthrough parameter is only taken again if it follows the parameter.
+        let mut unknowns = self.unknowns.iter();
+        while let Some(arg) = unknowns.next() {
+            if !config.unknown_passthrough {
+                return Err(ParsingError::UnrepresentableOption(arg.to_string()));
+            }
+            args.push(arg.to_string());
+            if config.passthrough_params.contains(arg.trim_start_matches(['-', '+', '/'])) {
+                match unknowns.next() {
+                    Some(value) => args.push(value.to_string()),
+                    None => return Err(ParsingError::UnrepresentableOption(arg.to_string())),
+                }
+            }
+        }
+
+        // A stored marker is already part of the unnamed parameters, at the position where it has to end up.
+        let stored_marker = if config.dh_marker && config.store_dh_marker {
+            self.unnameds.iter().position(|x| x == "--")
+        } else {
+            None
+        };
+        let ambiguous = self.unnameds.iter().enumerate()
+            .take(stored_marker.unwrap_or(self.unnameds.len()))
+            .find(|x| !parses_as_unnamed(config, x.1, x.0));
+        if let Some((_, value)) = ambiguous {
+            if !config.dh_marker || config.store_dh_marker || !self.subcommand.is_empty() {
+                return Err(ParsingError::UnrepresentableArgument(value.to_string()));
+            }
+            args.push(String::from("--"));
+        }
+        args.extend(self.unnameds.iter().cloned());
+
+        args.extend(self.subcommand.iter().cloned());
+        Ok(args)
+    }
+
+    /// Adds the arguments returned by `to_args`, except for the name of the command, to the `command`.
+    ///
+    /// Returns the error of `to_args` if the arguments cannot be produced, in which case the `command` is left unchanged.
+    pub fn apply_to_command<'a>(&self, config: &ParsingConfig, command: &'a mut Command) -> Result<&'a mut Command, ParsingError> {
+        Ok(command.args(self.to_args(config)?.into_iter().skip(1)))
+    }
+}
@@ -0,0 +1,211 @@
+use event::EventKind;
+use parsing_error::ParsingError;
+use parsing_config::ArgDesc;
+use parsing_config::ParsingConfig;
+use parsing_config::SingleHyphenMode;
+use parse::Parser;
+use parse::name_starts_with;
+use parse::parse_option_name;
+use parse::resolve_target;
+
+
+/// Identifies what a completion candidate represents.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CandidateKind {
+    /// The candidate specifies a flag.
+    Flag,
+
+    /// The candidate specifies a parameter, and a value is expected after it.
+    Param,
+
+    /// The candidate is a value for a parameter.
+    Value,
+
+    /// The candidate is a subcommand.
+    Subcommand,
+}
+
+
+/// A suggestion for the word that is being typed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Candidate {
+    pub(crate) value: String,
+    pub(crate) description: Option<String>,
+    pub(crate) kind: CandidateKind,
+}
+
+impl Candidate {
+    /// Returns the complete word that is suggested.
+    pub fn value(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Returns the description of the option or subcommand that the candidate specifies, if it has one.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns what the candidate represents.
+    pub fn kind(&self) -> CandidateKind {
+        self.kind
+    }
+}
+
+
+/// Describes what the word that is being typed is expected to be, according to the words before it.
+pub(crate) enum Position {
+    /// The word can be an option, an unnamed parameter or, if the flag is `true`, a subcommand.
+    Any(bool),
+
+    /// The word is the value of the parameter with the name.
+    Value(String),
+
+    /// The word is an unnamed parameter or belongs to a subcommand, so nothing can be suggested.
+    Nothing,
+}
+
+/// Interprets the words before the one that is being typed.
+/// The first word is the name of the command.
+pub(crate) fn position<I: Iterator<Item=String>>(config: &ParsingConfig, args: I) -> Position {
+    let mut unnamed_count = 0;
+    for event in Parser::new(args, config) {
+        match event {
+            Ok(event) => match event.kind {
+                EventKind::Unnamed(_) => unnamed_count += 1,
+                EventKind::Marker | EventKind::Subcommand(..) => return Position::Nothing,
+                _ => {},
+            },
+            Err(ParsingError::MissingArgument(target)) | Err(ParsingError::MissingArgumentAlias(target, _)) => return Position::Value(target),
+            Err(_) => break,
+        }
+    }
+    Position::Any(!config.subcmd_index || unnamed_count == config.subcmd_index_value)
+}
+
+fn candidate(config: &ParsingConfig, value: String, target: &str, kind: CandidateKind) -> Candidate {
+    Candidate {
+        value,
+        description: config.descriptions.get(target).cloned(),
+        kind,
+    }
+}
+
+/// Returns the candidates for a value of the parameter with the `target` name, each preceded by the `prefix`.
+fn value_candidates(config: &ParsingConfig, target: &str, prefix: &str, word: &str) -> Vec<Candidate> {
+    let values = match (config.choices.get(target), config.value_completers.get(target)) {
+        (Some(choices), _) => choices.clone(),
+        (None, Some(completer)) => completer(word),
+        (None, None) => Vec::new(),
+    };
+    values.iter()
+        .filter(|x| name_starts_with(config, x, word))
+        .map(|x| Candidate { value: format!("{}{}", prefix, x), description: None, kind: CandidateKind::Value })
+        .collect()
+}
+
+/// Returns the candidates for long options, each preceded by the `prefix`.
+fn long_candidates(config: &ParsingConfig, prefix: &str, word: &str, assignment: bool) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+    for (name, desc) in config.options.iter() {
+        if name.chars().count() < 2 || config.hidden.contains(name) || !name_starts_with(config, name, word) {
+            continue;
+        }
+        match desc {
+            ArgDesc::Flag => candidates.push(candidate(config, format!("{}{}", prefix, name), name, CandidateKind::Flag)),
+            ArgDesc::Param(_) | ArgDesc::OptionalParam(_) => {
+                let value = if assignment {
+                    format!("{}{}=", prefix, name)
+                } else {
+                    format!("{}{}", prefix, name)
+                };
+                candidates.push(candidate(config, value, name, CandidateKind::Param));
+            },
+            ArgDesc::Alias(_) => {},
+        }
+    }
+    candidates
+}
+
+/// Returns the candidates that add one option to a cluster of single hyphen options.
+fn cluster_candidates(config: &ParsingConfig, word: &str) -> Vec<Candidate> {
+    let mut candidates = Vec::new();
+    let cluster = &word[1..];
+    for opt in cluster.chars() {
+        match config.options.get(&opt.to_string()) {
+            Some(desc) if !resolve_target(config, "", desc).2 => return candidates,
+            Some(_) => {},
+            None => return candidates,
+        }
+    }
+    for (name, desc) in config.options.iter() {
+        let mut chars = name.chars();
+        let opt = match (chars.next(), chars.next()) {
+            (Some(opt), None) if opt.is_alphabetic() => opt,
+            _ => continue,
+        };
+        if config.hidden.contains(name) || cluster.contains(opt) {
+            continue;
+        }
+        let (target, _, flag) = resolve_target(config, name, desc);
+        let kind = if flag {
+            CandidateKind::Flag
+        } else {
+            CandidateKind::Param
+        };
+        candidates.push(candidate(config, format!("{}{}", word, opt), target, kind));
+    }
+    candidates
+}
+
+/// Returns the candidates for the word that is being typed, assuming it is the word after the `position`.
+pub(crate) fn complete_word(config: &ParsingConfig, position: Position, word: &str) -> Vec<Candidate> {
+    let mut candidates = match position {
+        Position::Nothing => Vec::new(),
+        Position::Value(target) => value_candidates(config, &target, "", word),
+        Position::Any(subcmd) => {
+            if (config.dh_syntax || config.dha_syntax) && word.starts_with("--") {
+                match word.find('=') {
+                    Some(eq_index) if config.dha_syntax => match parse_option_name(config, &word[2..eq_index]) {
+                        Ok((name, desc)) => {
+                            let target = resolve_target(config, name, desc).0;
+                            value_candidates(config, target, &word[..eq_index+1], &word[eq_index+1..])
+                        },
+                        Err(_) => Vec::new(),
+                    },
+                    Some(_) => Vec::new(),
+                    None => long_candidates(config, "--", &word[2..], !config.dh_syntax),
+                }
+            } else if config.sh_syntax && word.starts_with('-') {
+                let mut candidates = cluster_candidates(config, word);
+                if config.sh_mode != SingleHyphenMode::Clustered {
+                    candidates.extend(long_candidates(config, "-", &word[1..], false));
+                }
+                candidates
+            } else if subcmd {
+                config.subcmds.iter()
+                    .filter(|x| name_starts_with(config, x, word))
+                    .map(|x| candidate(config, x.to_string(), x, CandidateKind::Subcommand))
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        },
+    };
+    candidates.sort_unstable_by(|a, b| a.value.cmp(&b.value));
+    candidates
+}
+
+
+/// Returns the suggestions for the word that is being typed, which can be partially typed or empty.
+///
+/// The `args` are the words before the one that is being typed, the first of which is the name of the command.
+/// Long options, clusters of single hyphen options, values of parameters and subcommands are suggested, depending on the enabled syntax and on what the previous words expect.
+/// Parameters are suggested in the double hyphen assignment form, as in "--NAME=", if double hyphen syntax is disabled.
+/// Hidden options and aliases with a name of more than one character are never suggested.
+///
+/// The candidates are sorted by their value.
+pub fn complete<I: Iterator<Item=String>>(config: &ParsingConfig, args: I, word: &str) -> Vec<Candidate> {
+    complete_word(config, position(config, args), word)
+}
@@ -0,0 +1,132 @@
+use std::io::Error as IoError;
+use std::io::Write;
+
+use parsing_config::ParsingConfig;
+use tokenize::tokenize;
+use complete::complete;
+
+
+/// The name of the environment variable through which a completion script calls the program back.
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+
+/// Identifies a shell for which completions can be generated.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Shell {
+    /// The Bourne Again shell.
+    Bash,
+
+    /// The Z shell.
+    Zsh,
+
+    /// The friendly interactive shell.
+    Fish,
+}
+
+impl Shell {
+    /// Returns the shell with the `name`, which is "bash", "zsh" or "fish".
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            _ => None,
+        }
+    }
+
+    /// Returns the script that registers completions for the command with the `name` in this shell.
+    ///
+    /// The script calls the command back to compute the candidates every time completion is requested,
+    /// so it only has to be generated once and never goes stale.
+    pub fn bootstrap_script(&self, name: &str) -> String {
+        let function: String = name.chars().map(|x| if x.is_ascii_alphanumeric() { x } else { '_' }).collect();
+        match self {
+            Shell::Bash => format!(
+                "_clargs_{function}() {{\n    local IFS=$'\\n'\n    COMPREPLY=($({var}=bash \"${{COMP_WORDS[0]}}\" -- \"${{COMP_LINE:0:$COMP_POINT}}\"))\n}}\ncomplete -o default -F _clargs_{function} {name}\n",
+                function = function, var = COMPLETE_VAR, name = name,
+            ),
+            Shell::Zsh => format!(
+                "#compdef {name}\n_clargs_{function}() {{\n    local -a candidates\n    candidates=(\"${{(@f)$({var}=zsh \"${{words[1]}}\" -- \"${{BUFFER[1,$CURSOR]}}\")}}\")\n    _describe 'values' candidates\n}}\ncompdef _clargs_{function} {name}\n",
+                function = function, var = COMPLETE_VAR, name = name,
+            ),
+            Shell::Fish => format!(
+                "complete -c {name} -f -a '(env {var}=fish {name} -- (commandline -cp))'\n",
+                var = COMPLETE_VAR, name = name,
+            ),
+        }
+    }
+}
+
+
+/// Answers a completion request from a script that was generated by `Shell::bootstrap_script`.
+///
+/// The `shell` is the value of the `COMPLETE` environment variable and the `args` are the arguments the program was called with, including its name.
+/// If the `shell` is `None`, the program was not called for completion and `false` is returned without writing anything.
+/// Otherwise `true` is returned, after which the program should exit without doing anything else.
+///
+/// If the only argument is the name of the program, the bootstrap script for the `shell` is written to the `output`.
+/// Otherwise the arguments are expected to be a double hyphen followed by the command line up to the cursor,
+/// and the candidates that `complete` returns for it are written to the `output`, one per line.
+/// Unknown shells and invalid requests produce no output.
+pub fn complete_env<I: Iterator<Item=String>, W: Write>(config: &ParsingConfig, shell: Option<&str>, mut args: I, output: &mut W) -> Result<bool, IoError> {
+    let shell = match shell {
+        Some(shell) => shell,
+        None => return Ok(false),
+    };
+    let shell = match Shell::from_name(shell) {
+        Some(shell) => shell,
+        None => return Ok(true),
+    };
+    let name = args.next().unwrap_or_default();
+
+    let line = match (args.next(), args.next()) {
+        (None, _) => {
+            let name = name.rsplit(['/', '\\']).next().unwrap_or_default();
+            output.write_all(shell.bootstrap_script(name).as_bytes())?;
+            return Ok(true);
+        },
+        (Some(ref marker), Some(line)) if marker == "--" => line,
+        _ => return Ok(true),
+    };
+
+    let mut words = match tokenize(&line) {
+        Ok(words) => words,
+        Err(_) => return Ok(true),
+    };
+    let word = if line.ends_with(char::is_whitespace) {
+        String::new()
+    } else {
+        words.pop().unwrap_or_default()
+    };
+    if words.is_empty() {
+        return Ok(true);
+    }
+    // Bash replaces only the part of the word after the last word break.
+    let replaced = match shell {
+        Shell::Bash => word.rfind(['=', ':']).map_or(0, |x| x + 1),
+        _ => 0,
+    };
+
+    for candidate in complete(config, words.into_iter(), &word) {
+        let value = &candidate.value()[replaced..];
+        match (shell, candidate.description()) {
+            (Shell::Zsh, Some(description)) => writeln!(output, "{}:{}", value.replace(':', "\\:"), description)?,
+            (Shell::Zsh, None) => writeln!(output, "{}", value.replace(':', "\\:"))?,
+            (Shell::Fish, Some(description)) => writeln!(output, "{}\t{}", value, description)?,
+            _ => writeln!(output, "{}", value)?,
+        }
+    }
+    Ok(true)
+}
+
+/// Answers a completion request if the program was called for one, using the `COMPLETE` environment variable, the program's arguments and the standard output.
+///
+/// Returns `true` if a request was answered, in which case the program should exit right away.
+/// See `complete_env` for the details.
+pub fn complete_from_env(config: &ParsingConfig) -> bool {
+    let shell = std::env::var(COMPLETE_VAR).ok();
+    let stdout = std::io::stdout();
+    let mut output = stdout.lock();
+    complete_env(config, shell.as_deref(), std::env::args(), &mut output).unwrap_or(true)
+}
@@ -0,0 +1,443 @@
+use config_error::ConfigError;
+use parsing_config::ArgDesc;
+use parsing_config::ParsingConfig;
+use parsing_config::SingleHyphenMode;
+
+
+/// Builds a `ParsingConfig` through chained method calls.
+///
+/// Flags, parameters, aliases and subcommands are added in the order in which the methods are called.
+/// Methods such as `short`, `alias` and `required` apply to the flag or parameter that was added last, `alias` and `description` can also apply to a subcommand.
+/// Methods such as `exact`, `deprecated` and `hidden` apply to the alias that was added last instead, if it was added after that flag or parameter.
+/// The first invalid or duplicated name, or the first modifier that does not follow a name it can apply to, is remembered and returned by `build`.
+/// All calls after it have no effect.
+pub struct ConfigBuilder {
+    config: ParsingConfig,
+    last: Option<String>,
+    last_alias: Option<String>,
+    last_subcmd: Option<String>,
+    error: Option<ConfigError>,
+}
+
+/// Accepts any flag or parameter.
+fn any(_: &ArgDesc) -> bool {
+    true
+}
+
+/// Accepts parameters, with or without an optional value.
+fn param(desc: &ArgDesc) -> bool {
+    matches!(desc, ArgDesc::Param(_) | ArgDesc::OptionalParam(_))
+}
+
+/// Returns an error for the first name that differs only in case from a name before it, if the `config` is case-insensitive.
+/// The names are checked in the order in which a deserialized configuration adds them: options, subcommands and subcommand aliases, each sorted.
+fn check_case(config: &ParsingConfig) -> Result<(), ConfigError> {
+    if !config.case_insensitive {
+        return Ok(());
+    }
+    let mut groups: Vec<Vec<&String>> = vec![config.options.keys().collect(), config.subcmds.iter().collect(), config.subcmd_aliases.keys().collect()];
+    let mut seen: Vec<&String> = Vec::new();
+    for group in groups.iter_mut() {
+        group.sort_unstable();
+        for name in group.iter() {
+            if name.chars().count() > 1 {
+                let lowercase = name.to_lowercase();
+                if let Some(other) = seen.iter().filter(|x| x.to_lowercase() == lowercase).min() {
+                    return Err(ConfigError::NameTakenIgnoringCase(name.to_string(), other.to_string()));
+                }
+            }
+            seen.push(name);
+        }
+    }
+    Ok(())
+}
+
+
+impl ParsingConfig {
+    /// Returns a `ConfigBuilder` that starts from the same settings as `new`.
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new(Self::new())
+    }
+
+    /// Returns a `ConfigBuilder` that starts from the same settings as `new_all_disabled`.
+    pub fn builder_all_disabled() -> ConfigBuilder {
+        ConfigBuilder::new(Self::new_all_disabled())
+    }
+}
+
+impl ConfigBuilder {
+    fn new(config: ParsingConfig) -> Self {
+        Self {
+            config,
+            last: None,
+            last_alias: None,
+            last_subcmd: None,
+            error: None,
+        }
+    }
+
+    /// Records the result of adding a name.
+    /// The name becomes the target of the following modifiers if it was added successfully.
+    fn record(&mut self, name: String, result: Result<(), ConfigError>, option: bool) {
+        self.last_alias = None;
+        self.last_subcmd = None;
+        match result {
+            Ok(()) if option => self.last = Some(name),
+            Ok(()) => {
+                self.last = None;
+                self.last_subcmd = Some(name);
+            },
+            Err(error) => {
+                self.last = None;
+                self.error = Some(error);
+            },
+        }
+    }
+
+    /// Returns the name of the flag or parameter that was added last, if it is accepted by the `check`.
+    /// Otherwise the misplaced modifier is recorded as the error.
+    fn last(&mut self, modifier: &str, check: fn(&ArgDesc) -> bool) -> Option<String> {
+        let found = self.last.clone().filter(|x| self.config.options.get(x).is_some_and(check));
+        if found.is_none() {
+            self.error = Some(ConfigError::MisplacedModifier(modifier.to_string()));
+        }
+        found
+    }
+
+    /// Returns the name of the alias that was added last if it was added after the last flag or parameter, or the name of that flag or parameter otherwise.
+    /// If there is neither, the misplaced modifier is recorded as the error.
+    fn last_name(&mut self, modifier: &str) -> Option<String> {
+        match self.last_alias {
+            Some(ref name) => Some(name.clone()),
+            None => self.last(modifier, any),
+        }
+    }
+
+
+    /// Adds a flag.
+    pub fn flag(mut self, name: impl Into<String>) -> Self {
+        if self.error.is_none() {
+            let name = name.into();
+            let result = self.config.try_add_flag(name.clone());
+            self.record(name, result, true);
+        }
+        self
+    }
+
+    /// Adds a parameter that is not required.
+    /// Use `required` to make it required.
+    pub fn param(mut self, name: impl Into<String>) -> Self {
+        if self.error.is_none() {
+            let name = name.into();
+            let result = self.config.try_add_param(name.clone(), false);
+            self.record(name, result, true);
+        }
+        self
+    }
+
+    /// Adds a parameter with an optional value.
+    /// The parameter is set to the `implicit_value` when it is specified without a value.
+    pub fn optional_param(mut self, name: impl Into<String>, implicit_value: impl Into<String>) -> Self {
+        if self.error.is_none() {
+            let name = name.into();
+            let result = self.config.try_add_optional_param(name.clone(), implicit_value.into());
+            self.record(name, result, true);
+        }
+        self
+    }
+
+    /// Adds a subcommand.
+    pub fn subcommand(mut self, name: impl Into<String>) -> Self {
+        if self.error.is_none() {
+            let name = name.into();
+            let result = self.config.try_add_subcommand(name.clone());
+            self.record(name, result, false);
+        }
+        self
+    }
+
+    /// Adds an alias to the flag, parameter or subcommand that was added last.
+    pub fn alias(mut self, name: impl Into<String>) -> Self {
+        if self.error.is_none() {
+            let name = name.into();
+            match self.last_subcmd.clone() {
+                Some(target) => if let Err(error) = self.config.try_add_subcommand_alias(name, target) {
+                    self.error = Some(error);
+                },
+                None => self.option_alias(name, "alias"),
+            }
+        }
+        self
+    }
+
+    /// Adds an alias to the flag or parameter that was added last.
+    fn option_alias(&mut self, name: String, modifier: &str) {
+        if let Some(target) = self.last(modifier, any) {
+            match self.config.try_add_alias(name.clone(), target) {
+                Ok(()) => self.last_alias = Some(name),
+                Err(error) => self.error = Some(error),
+            }
+        }
+    }
+
+    /// Adds an alias with a name of one character to the flag or parameter that was added last.
+    pub fn short(mut self, name: char) -> Self {
+        if self.error.is_none() {
+            self.option_alias(name.to_string(), "short");
+        }
+        self
+    }
+
+    /// Makes the parameter that was added last required.
+    /// It must be a parameter without an optional value.
+    pub fn required(mut self) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last("required", |x| matches!(x, ArgDesc::Param(_))) {
+                self.config.options.insert(name, ArgDesc::Param(true));
+            }
+        }
+        self
+    }
+
+    /// Disables completion for the flag, parameter or alias that was added last, so it can only be specified by its full name.
+    pub fn exact(mut self) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last_name("exact") {
+                self.config.set_option_completion_for(name, false);
+            }
+        }
+        self
+    }
+
+    /// Marks the flag, parameter or alias that was added last as deprecated.
+    /// Specifying it produces a warning, which mentions the `replacement` if there is one.
+    pub fn deprecated(mut self, replacement: Option<&str>) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last_name("deprecated") {
+                self.config.set_deprecated(name, true, replacement.map(|x| x.to_string()));
+            }
+        }
+        self
+    }
+
+    /// Hides the flag, parameter or alias that was added last, so it is never completed.
+    pub fn hidden(mut self) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last_name("hidden") {
+                self.config.set_hidden(name, true);
+            }
+        }
+        self
+    }
+
+    /// Marks the parameter that was added last as secret, so its value is not shown when it is prompted for.
+    pub fn secret(mut self) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last("secret", param) {
+                self.config.set_secret(name, true);
+            }
+        }
+        self
+    }
+
+    /// Declares the flag or parameter that was added last global, so it is inherited by subcommands.
+    pub fn global(mut self) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last("global", any) {
+                self.config.set_global(name, true);
+            }
+        }
+        self
+    }
+
+    /// Sets the description of the flag, parameter or subcommand that was added last.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        if self.error.is_none() {
+            let name = match self.last_subcmd {
+                Some(ref name) => Some(name.clone()),
+                None => self.last("description", any),
+            };
+            if let Some(name) = name {
+                self.config.set_description(name, description.into());
+            }
+        }
+        self
+    }
+
+    /// Sets the function that computes the completion candidates for the values of the parameter that was added last.
+    pub fn value_completer<F: Fn(&str) -> Vec<String> + Send + Sync + 'static>(mut self, completer: F) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last("value_completer", param) {
+                self.config.set_value_completer(name, completer);
+            }
+        }
+        self
+    }
+
+    /// Restricts the values of the parameter that was added last to the `choices`.
+    pub fn choices<S: Into<String>>(mut self, choices: impl IntoIterator<Item=S>) -> Self {
+        if self.error.is_none() {
+            if let Some(name) = self.last("choices", param) {
+                self.config.set_choices(name, choices.into_iter().map(|x| x.into()).collect());
+            }
+        }
+        self
+    }
+
+
+    /// Enables or disables the double hyphen marker.
+    pub fn double_hyphen_marker(mut self, value: bool) -> Self {
+        self.config.set_double_hyphen_marker(value);
+        self
+    }
+
+    /// Enables or disables storing of the double hyphen marker.
+    pub fn store_double_hyphen_marker(mut self, value: bool) -> Self {
+        self.config.set_store_double_hyphen_marker(value);
+        self
+    }
+
+    /// Enables or disables single hyphen syntax.
+    pub fn single_hyphen_syntax(mut self, value: bool) -> Self {
+        self.config.set_single_hyphen_syntax(value);
+        self
+    }
+
+    /// Sets how arguments that start with a single hyphen are interpreted.
+    pub fn single_hyphen_mode(mut self, value: SingleHyphenMode) -> Self {
+        self.config.set_single_hyphen_mode(value);
+        self
+    }
+
+    /// Enables or disables double hyphen syntax.
+    pub fn double_hyphen_syntax(mut self, value: bool) -> Self {
+        self.config.set_double_hyphen_syntax(value);
+        self
+    }
+
+    /// Enables or disables double hyphen assignment syntax.
+    pub fn double_hyphen_assignment_syntax(mut self, value: bool) -> Self {
+        self.config.set_double_hyphen_assignment_syntax(value);
+        self
+    }
+
+    /// Enables or disables assignment syntax.
+    pub fn assignment_syntax(mut self, value: bool) -> Self {
+        self.config.set_assignment_syntax(value);
+        self
+    }
+
+    /// Enables or disables slash syntax.
+    pub fn slash_syntax(mut self, value: bool) -> Self {
+        self.config.set_slash_syntax(value);
+        self
+    }
+
+    /// Sets the character that separates the name from the value in slash syntax.
+    ///
+    /// If the `value` is neither a colon nor an equals sign, `build` returns `ConfigError::InvalidSetting`.
+    pub fn slash_separator(mut self, value: char) -> Self {
+        if value == ':' || value == '=' {
+            self.config.set_slash_separator(value);
+        } else if self.error.is_none() {
+            self.error = Some(ConfigError::InvalidSetting(String::from("slash separator")));
+        }
+        self
+    }
+
+    /// Enables or disables plus syntax.
+    pub fn plus_syntax(mut self, value: bool) -> Self {
+        self.config.set_plus_syntax(value);
+        self
+    }
+
+    /// Enables or disables single hyphen parameter stacking.
+    pub fn parameter_stacking(mut self, value: bool) -> Self {
+        self.config.set_parameter_stacking(value);
+        self
+    }
+
+    /// Enables or disables parameter duplication.
+    pub fn parameter_duplication(mut self, value: bool) -> Self {
+        self.config.set_parameter_duplication(value);
+        self
+    }
+
+    /// Adds the global options of the `parent`, see `ParsingConfig::inherit_globals`.
+    pub fn inherit_globals(mut self, parent: &ParsingConfig) -> Self {
+        self.config.inherit_globals(parent);
+        self
+    }
+
+    /// Enables or disables unknown option passthrough.
+    pub fn unknown_passthrough(mut self, value: bool) -> Self {
+        self.config.set_unknown_passthrough(value);
+        self
+    }
+
+    /// Declares that an unrecognized option with the `name` takes a value.
+    pub fn passthrough_param(mut self, name: impl Into<String>) -> Self {
+        self.config.add_passthrough_param(name.into());
+        self
+    }
+
+    /// Enables or disables argument completion.
+    pub fn option_completion(mut self, value: bool) -> Self {
+        self.config.set_option_completion(value);
+        self
+    }
+
+    /// Sets the minimum length of a prefix that completes to an option.
+    pub fn option_completion_min_length(mut self, value: usize) -> Self {
+        self.config.set_option_completion_min_length(value);
+        self
+    }
+
+    /// Enables or disables subcommand completion.
+    pub fn subcommand_completion(mut self, value: bool) -> Self {
+        self.config.set_subcommand_completion(value);
+        self
+    }
+
+    /// Enables or disables case-insensitive matching of option names, subcommand names and choices.
+    pub fn case_insensitive(mut self, value: bool) -> Self {
+        self.config.set_case_insensitive(value);
+        self
+    }
+
+    /// Enables or disables the index at which the subcommand is expected to be found in the unnamed parameters list.
+    pub fn subcommand_index(mut self, value: bool) -> Self {
+        self.config.set_subcommand_index(value);
+        self
+    }
+
+    /// Enables or disables the requirement of a subcommand.
+    pub fn subcommand_required(mut self, value: bool) -> Self {
+        self.config.set_subcommand_required(value);
+        self
+    }
+
+    /// Sets the index at which the subcommand is expected to be found in the unnamed parameters list.
+    pub fn subcommand_index_value(mut self, value: usize) -> Self {
+        self.config.set_subcommand_index_value(value);
+        self
+    }
+
+
+    /// Returns the finished `ParsingConfig`.
+    ///
+    /// Returns the first error that occurred while adding flags, parameters, aliases or subcommands or applying modifiers to them, if any.
+    /// Otherwise, if case-insensitive matching is enabled, returns `ConfigError::NameTakenIgnoringCase` for names that differ only in case, even if they were added before it was enabled.
+    ///
+    /// Note that `build` does not run `ParsingConfig::lint`, so problems such as unreachable options are not reported.
+    /// Neither are changes that are made to the returned `ParsingConfig` through its methods checked again, such as enabling case-insensitive matching afterwards.
+    pub fn build(self) -> Result<ParsingConfig, ConfigError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => {
+                check_case(&self.config)?;
+                Ok(self.config)
+            },
+        }
+    }
+}
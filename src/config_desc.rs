@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use config_error::ConfigError;
+use parsing_config::ArgDesc;
+use parsing_config::ParsingConfig;
+use parsing_config::SingleHyphenMode;
+
+
+/// The serialized form of a `ParsingConfig`, which is checked before it becomes a `ParsingConfig` again.
+#[derive(Deserialize)]
+pub struct ConfigDesc {
+    dh_marker: bool,
+    store_dh_marker: bool,
+    sh_syntax: bool,
+    sh_mode: SingleHyphenMode,
+    dh_syntax: bool,
+    dha_syntax: bool,
+    a_syntax: bool,
+    slash_syntax: bool,
+    slash_separator: char,
+    plus_syntax: bool,
+
+    param_stacking: bool,
+    param_duplication: bool,
+    unknown_passthrough: bool,
+
+    option_completion: bool,
+    subcmd_completion: bool,
+    completion_min_length: usize,
+    case_insensitive: bool,
+
+    subcmd_index: bool,
+    subcmd_required: bool,
+    subcmd_index_value: usize,
+
+    options: HashMap<String, ArgDesc>,
+    subcmds: HashSet<String>,
+    subcmd_aliases: HashMap<String, String>,
+    choices: HashMap<String, Vec<String>>,
+    no_completion: HashSet<String>,
+    deprecated: HashMap<String, Option<String>>,
+    hidden: HashSet<String>,
+    secrets: HashSet<String>,
+    globals: HashSet<String>,
+    passthrough_params: HashSet<String>,
+    descriptions: HashMap<String, String>,
+}
+
+/// Adds the aliases in the order in which their targets become available, since an alias can point to another alias.
+fn add_aliases<F>(aliases: HashMap<String, String>, exists: impl Fn(&ParsingConfig, &str) -> bool, config: &mut ParsingConfig, mut add: F) -> Result<(), ConfigError>
+    where F: FnMut(&mut ParsingConfig, String, String) -> Result<(), ConfigError>
+{
+    let mut aliases: Vec<(String, String)> = aliases.into_iter().collect();
+    aliases.sort_unstable();
+    while !aliases.is_empty() {
+        let (ready, waiting): (Vec<_>, Vec<_>) = aliases.into_iter().partition(|x| x.0 == x.1 || exists(config, &x.1));
+        if ready.is_empty() {
+            let (name, target) = waiting.into_iter().next().unwrap();
+            return Err(ConfigError::AliasTargetMissing(name, target));
+        }
+        for (name, target) in ready {
+            add(config, name, target)?;
+        }
+        aliases = waiting;
+    }
+    Ok(())
+}
+
+/// Returns an error for the `name` unless it passes the `check`.
+fn check_setting(name: &str, check: bool) -> Result<(), ConfigError> {
+    if check {
+        Ok(())
+    } else {
+        Err(ConfigError::InvalidSetting(name.to_string()))
+    }
+}
+
+impl TryFrom<ConfigDesc> for ParsingConfig {
+    type Error = ConfigError;
+
+    fn try_from(desc: ConfigDesc) -> Result<Self, ConfigError> {
+        let mut config = ParsingConfig::new();
+        config.dh_marker = desc.dh_marker;
+        config.store_dh_marker = desc.store_dh_marker;
+        config.sh_syntax = desc.sh_syntax;
+        config.sh_mode = desc.sh_mode;
+        config.dh_syntax = desc.dh_syntax;
+        config.dha_syntax = desc.dha_syntax;
+        config.a_syntax = desc.a_syntax;
+        config.slash_syntax = desc.slash_syntax;
+        check_setting("slash separator", desc.slash_separator == ':' || desc.slash_separator == '=')?;
+        config.slash_separator = desc.slash_separator;
+        config.plus_syntax = desc.plus_syntax;
+
+        config.param_stacking = desc.param_stacking;
+        config.param_duplication = desc.param_duplication;
+        config.unknown_passthrough = desc.unknown_passthrough;
+
+        config.option_completion = desc.option_completion;
+        config.subcmd_completion = desc.subcmd_completion;
+        config.completion_min_length = desc.completion_min_length;
+
+        config.subcmd_index = desc.subcmd_index;
+        config.subcmd_required = desc.subcmd_required;
+        config.subcmd_index_value = desc.subcmd_index_value;
+
+        let mut options: Vec<(String, ArgDesc)> = desc.options.into_iter().collect();
+        options.sort_unstable();
+        let mut aliases = HashMap::new();
+        for (name, arg_desc) in options {
+            match arg_desc {
+                ArgDesc::Flag => config.try_add_flag(name)?,
+                ArgDesc::Param(required) => config.try_add_param(name, required)?,
+                ArgDesc::OptionalParam(implicit_value) => config.try_add_optional_param(name, implicit_value)?,
+                ArgDesc::Alias(target) => {
+                    aliases.insert(name, target);
+                },
+            }
+        }
+        add_aliases(aliases, |config, x| config.options.contains_key(x), &mut config, ParsingConfig::try_add_alias)?;
+
+        let mut subcmds: Vec<String> = desc.subcmds.into_iter().collect();
+        subcmds.sort_unstable();
+        for name in subcmds {
+            config.try_add_subcommand(name)?;
+        }
+        let exists = |config: &ParsingConfig, x: &str| config.subcmds.contains(x) || config.subcmd_aliases.contains_key(x);
+        add_aliases(desc.subcmd_aliases, exists, &mut config, ParsingConfig::try_add_subcommand_alias)?;
+
+        // Enabled only now, since names that differ only in case may have been added before it was enabled.
+        config.case_insensitive = desc.case_insensitive;
+
+        let is_param = |config: &ParsingConfig, name: &str| matches!(config.options.get(name), Some(ArgDesc::Param(_)) | Some(ArgDesc::OptionalParam(_)));
+        for name in desc.choices.keys().chain(desc.secrets.iter()) {
+            check_setting(name, is_param(&config, name))?;
+        }
+        for name in desc.no_completion.iter().chain(desc.deprecated.keys()).chain(desc.hidden.iter()) {
+            check_setting(name, config.options.contains_key(name))?;
+        }
+        for name in desc.globals.iter() {
+            check_setting(name, !matches!(config.options.get(name), Some(ArgDesc::Alias(_)) | None))?;
+        }
+        for name in desc.descriptions.keys() {
+            check_setting(name, config.options.contains_key(name) || config.subcmds.contains(name))?;
+        }
+        config.choices = desc.choices;
+        config.no_completion = desc.no_completion;
+        config.deprecated = desc.deprecated;
+        config.hidden = desc.hidden;
+        config.secrets = desc.secrets;
+        config.globals = desc.globals;
+        config.passthrough_params = desc.passthrough_params;
+        config.descriptions = desc.descriptions;
+        Ok(config)
+    }
+}
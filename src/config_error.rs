@@ -0,0 +1,77 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Error as FmtError;
+
+
+/// Returned when a flag, parameter, alias or subcommand cannot be added to a `ParsingConfig`.
+#[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConfigError {
+    /// Indicates that a name is not valid for a flag, parameter, alias or subcommand.
+    ///
+    /// The associated string is the aforementioned name.
+    InvalidName(String),
+
+    /// Indicates that a name is already used by another flag, parameter, alias or subcommand.
+    ///
+    /// The associated string is the aforementioned name.
+    NameTaken(String),
+
+    /// Indicates that a name differs only in case from a name that is already used, while case-insensitive matching is enabled.
+    /// Names of one character are exempt, because they are matched case-sensitively in clusters and plus syntax.
+    ///
+    /// The first associated string is the aforementioned name.
+    /// The second associated string is the name that is already used.
+    NameTakenIgnoringCase(String, String),
+
+    /// Indicates that an alias points to a target which is not a flag, parameter or other alias,
+    /// or that a subcommand alias points to a target which is not a subcommand or other subcommand alias.
+    ///
+    /// The first associated string is the name of the aforementioned alias.
+    /// The second associated string is the name of the target.
+    AliasTargetMissing(String, String),
+
+    /// Indicates that an alias points to itself.
+    ///
+    /// The associated string is the name of the aforementioned alias.
+    AliasSelfReference(String),
+
+    /// Indicates that a `ConfigBuilder` modifier, such as `required`, does not follow a name it can apply to.
+    ///
+    /// The associated string is the name of the aforementioned modifier.
+    MisplacedModifier(String),
+
+    /// Indicates that a deserialized `ParsingConfig` contains a setting that its setter would not accept,
+    /// such as choices for a name that is not a parameter.
+    ///
+    /// The associated string is the name the setting refers to.
+    InvalidSetting(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self {
+            ConfigError::InvalidName(name) => write!(f, "'{}' is not a valid name", name),
+            ConfigError::NameTaken(name) => write!(f, "name '{}' is already taken", name),
+            ConfigError::NameTakenIgnoringCase(name, other) => write!(f, "name '{}' differs only in case from '{}'", name, other),
+            ConfigError::AliasTargetMissing(name, target) => write!(f, "alias '{}' points to '{}', which does not exist", name, target),
+            ConfigError::AliasSelfReference(name) => write!(f, "alias '{}' points to itself", name),
+            ConfigError::MisplacedModifier(modifier) => write!(f, "'{}' does not follow a name it can apply to", modifier),
+            ConfigError::InvalidSetting(name) => write!(f, "invalid setting for '{}'", name),
+        }
+    }
+}
+
+impl Debug for ConfigError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        (self as &dyn Display).fmt(f)
+    }
+}
+
+impl Error for ConfigError {
+    fn cause(&self) -> Option<&dyn Error> {
+        None
+    }
+}
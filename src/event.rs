@@ -0,0 +1,110 @@
+use occurrence::Syntax;
+use external::ExternalSubcommand;
+
+
+/// Describes what a single event in a parsed argument list represents.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EventKind {
+    /// Indicates that a flag was specified.
+    ///
+    /// The associated string is the name of the aforementioned flag.
+    Flag(String),
+
+    /// Indicates that a flag was explicitly turned off.
+    ///
+    /// The associated string is the name of the aforementioned flag.
+    FlagOff(String),
+
+    /// Indicates that a value was assigned to a parameter.
+    ///
+    /// The first associated string is the name of the aforementioned parameter.
+    /// The second associated string is the value that was assigned to it.
+    Param(String, String),
+
+    /// Indicates that an unnamed parameter was specified.
+    ///
+    /// The associated string is the value of the aforementioned unnamed parameter.
+    Unnamed(String),
+
+    /// Indicates that an unrecognized option, or the value that followed it, was passed through.
+    /// This only occurs if unknown option passthrough is enabled.
+    ///
+    /// The associated string is the argument itself.
+    Unknown(String),
+
+    /// Indicates that the double hyphen marker was found.
+    /// All following arguments are unnamed parameters.
+    Marker,
+
+    /// Indicates that a subcommand was found.
+    ///
+    /// The associated string is the name of the aforementioned subcommand.
+    /// The associated vector of strings contains all the arguments that followed it.
+    Subcommand(String, Vec<String>),
+
+    /// Indicates that a subcommand implemented by a separate executable was found.
+    /// This only occurs when parsing with `parse_with_externals`.
+    ///
+    /// The associated value holds the name of the subcommand, the path of the executable and all the arguments that followed it.
+    External(ExternalSubcommand),
+}
+
+
+/// A single event in a parsed argument list.
+///
+/// Events are produced in the order in which they occur in the argument list.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Event {
+    pub(crate) kind: EventKind,
+    pub(crate) index: usize,
+    pub(crate) alias: Option<String>,
+    pub(crate) typed: Option<String>,
+    pub(crate) syntax: Option<Syntax>,
+    pub(crate) implicit: bool,
+}
+
+impl Event {
+    /// Returns a reference to what this event represents.
+    pub fn kind(&self) -> &EventKind {
+        &self.kind
+    }
+
+    /// Returns the index in the argument list of the argument that produced this event.
+    ///
+    /// The first argument, the name of the command, has index zero.
+    /// Multiple events can share the same index, for instance when several options are specified in one argument.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the name of the alias through which the option or subcommand was specified, if an alias was used.
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    /// Returns what the user actually typed to specify the option, which can be an alias or an abbreviation of its name.
+    ///
+    /// Returns `None` if the event does not represent an option.
+    pub fn typed(&self) -> Option<&str> {
+        self.typed.as_deref()
+    }
+
+    /// Returns the syntax through which the option was specified.
+    ///
+    /// Returns `None` if the event does not represent an option.
+    pub fn syntax(&self) -> Option<Syntax> {
+        self.syntax
+    }
+
+    /// Returns `true` if the event assigns the implicit value to a parameter with an optional value, because it was specified without one.
+    pub fn is_implicit(&self) -> bool {
+        self.implicit
+    }
+
+    /// Consumes the event and returns what it represents.
+    pub fn into_kind(self) -> EventKind {
+        self.kind
+    }
+}
@@ -0,0 +1,138 @@
+use std::env::consts::EXE_SUFFIX;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use parsing_error::ParsingError;
+use parsing_config::ParsingConfig;
+use parsed_args::ParsedArgs;
+use parse::Parser;
+
+
+/// A subcommand that is not part of the configuration but is implemented by a separate executable.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExternalSubcommand {
+    pub(crate) name: String,
+    pub(crate) path: PathBuf,
+    pub(crate) args: Vec<String>,
+}
+
+impl ExternalSubcommand {
+    /// Returns the name of the subcommand as it was typed.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the path of the executable that implements the subcommand.
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    /// Returns the arguments that followed the subcommand, which should be passed on to the executable.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+}
+
+
+/// Finds executables that implement subcommands of a tool, in the way that `git foo` runs `git-foo`.
+///
+/// The executable for the subcommand `NAME` of the tool `TOOL` is called `TOOL-NAME`, followed by the executable suffix of the platform.
+/// The directories of the search path are searched in order, and the first match wins.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExternalSubcommands {
+    prefix: String,
+    dirs: Vec<PathBuf>,
+}
+
+impl ExternalSubcommands {
+    /// Constructs and returns an `ExternalSubcommands` object for the tool with the `name`,
+    /// which searches the directories in the `search_path`, a list in the format of the `PATH` environment variable.
+    pub fn new(name: &str, search_path: &OsStr) -> Self {
+        Self {
+            prefix: format!("{}-", name),
+            dirs: std::env::split_paths(search_path).filter(|x| !x.as_os_str().is_empty()).collect(),
+        }
+    }
+
+    /// Constructs and returns an `ExternalSubcommands` object for the tool with the `name`, which searches the directories in the `PATH` environment variable.
+    pub fn from_env(name: &str) -> Self {
+        Self::new(name, &std::env::var_os("PATH").unwrap_or_default())
+    }
+
+    /// Returns the path of the executable that implements the subcommand with the `name`, or `None` if there is none.
+    ///
+    /// Names that are empty or contain a path separator never match.
+    pub fn find(&self, name: &str) -> Option<PathBuf> {
+        if name.is_empty() || name.contains(std::path::is_separator) {
+            return None;
+        }
+        let file_name = format!("{}{}{}", self.prefix, name, EXE_SUFFIX);
+        self.dirs.iter()
+            .map(|dir| dir.join(&file_name))
+            .find(|path| is_executable(path))
+    }
+
+    /// Returns the names of all subcommands for which an executable can be found, sorted and without duplicates.
+    ///
+    /// This can be used to include the discovered subcommands in a help message.
+    pub fn list(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        for dir in self.dirs.iter() {
+            let entries = match fs::read_dir(dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+            for entry in entries.flatten() {
+                let file_name = entry.file_name();
+                let name = match file_name.to_str().and_then(|x| x.strip_prefix(self.prefix.as_str())).and_then(|x| x.strip_suffix(EXE_SUFFIX)) {
+                    Some(name) if !name.is_empty() => name,
+                    _ => continue,
+                };
+                if is_executable(&entry.path()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).is_ok_and(|x| x.is_file() && x.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|x| x.is_file())
+}
+
+
+/// Parses the argument list according to the provided configuration, and looks for unknown subcommands among the `externals`.
+/// The result is either an error or the parsed arguments.
+///
+/// An argument that is not a subcommand of the configuration, at the position where a subcommand is expected, is looked up by its exact name.
+/// That position is the subcommand index if it is enabled, or the first unnamed parameter otherwise.
+/// If an executable is found, the argument is interpreted as a subcommand and `ParsedArgs::external_subcommand` returns its path and arguments.
+/// Otherwise the argument is interpreted as it would be by `parse`.
+///
+/// This changes how ordinary unnamed parameters are read.
+/// Without the subcommand index, the first unnamed parameter is looked up among the `externals`, so a value such as a file name that happens to match an executable is read as an external subcommand.
+/// All the arguments after it then become arguments of that subcommand, instead of being parsed as options and unnamed parameters.
+///
+/// Note that the argument list passed to this function cannot contain non-unicode characters.
+pub fn parse_with_externals<I: Iterator<Item=String>>(args: I, config: &ParsingConfig, externals: &ExternalSubcommands) -> Result<ParsedArgs, ParsingError> {
+    let mut parser = Parser::new(args, config);
+    parser.externals = Some(externals);
+    let mut parsed_args = ParsedArgs::new(std::mem::take(&mut parser.name));
+    for event in parser {
+        parsed_args.apply(event?, config);
+    }
+    Ok(parsed_args)
+}
@@ -0,0 +1,100 @@
+use event::EventKind;
+use parsing_error::ParsingError;
+use parsing_config::ParsingConfig;
+use parsed_args::ParsedArgs;
+use parse::Parser;
+
+
+/// Iterates over the unnamed parameters of an argument list while it is being parsed.
+///
+/// Options are interpreted as they are encountered, and their results can be inspected through `parsed_args`.
+/// The argument list has already been validated by `parse_lazy`, so the iterator only returns an error if the arguments changed between both passes.
+/// If an error is returned, the iterator returns nothing afterwards.
+///
+/// The iterator ends at a subcommand, whose arguments can then be taken with `into_subcommand_args`.
+pub struct LazyArgs<'a, I> {
+    parser: Parser<'a, I>,
+    config: &'a ParsingConfig,
+    parsed_args: ParsedArgs,
+    ready: Option<String>,
+}
+
+impl<'a, I: Iterator<Item=String>> LazyArgs<'a, I> {
+    pub(crate) fn new(mut parser: Parser<'a, I>, config: &'a ParsingConfig) -> Result<Self, ParsingError> {
+        parser.leave_rest = true;
+        let parsed_args = ParsedArgs::new(std::mem::take(&mut parser.name));
+        let mut lazy_args = Self {
+            parser,
+            config,
+            parsed_args,
+            ready: None,
+        };
+        while lazy_args.ready.is_none() {
+            match lazy_args.advance() {
+                Some(Ok(true)) => break,
+                Some(Ok(false)) => {},
+                Some(Err(error)) => return Err(error),
+                None => break,
+            }
+        }
+        Ok(lazy_args)
+    }
+
+    /// Interprets the next event.
+    /// Returns `true` if the event ends the leading options.
+    fn advance(&mut self) -> Option<Result<bool, ParsingError>> {
+        let event = match self.parser.next()? {
+            Ok(event) => event,
+            Err(error) => return Some(Err(error)),
+        };
+        let ends_options = match event.kind {
+            EventKind::Unnamed(value) => {
+                self.ready = Some(value);
+                return Some(Ok(true));
+            },
+            EventKind::Marker => {
+                if self.config.store_dh_marker {
+                    self.ready = Some(String::from("--"));
+                }
+                return Some(Ok(true));
+            },
+            EventKind::Subcommand(..) | EventKind::External(_) => true,
+            _ => false,
+        };
+        self.parsed_args.apply(event, self.config);
+        Some(Ok(ends_options))
+    }
+
+    /// Returns the results of the options that have been interpreted so far.
+    ///
+    /// The unnamed parameters are never stored, so the returned list of unnamed parameters is always empty.
+    /// Neither are the arguments of a subcommand, so the subcommand's argument list only holds its name.
+    pub fn parsed_args(&self) -> &ParsedArgs {
+        &self.parsed_args
+    }
+
+    /// Consumes the iterator and returns the arguments that follow the subcommand, without interpreting them.
+    ///
+    /// Returns `None` if no subcommand was found.
+    /// Call this once the iterator is exhausted, since the subcommand is only found when the iterator reaches it.
+    pub fn into_subcommand_args(self) -> Option<I> {
+        if self.parsed_args.subcommand.is_empty() {
+            None
+        } else {
+            Some(self.parser.into_args())
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item=String>> Iterator for LazyArgs<'a, I> {
+    type Item = Result<String, ParsingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.ready.is_none() {
+            if let Err(error) = self.advance()? {
+                return Some(Err(error));
+            }
+        }
+        self.ready.take().map(Ok)
+    }
+}
@@ -6,6 +6,7 @@
 //! Before parsing an argument list a `ParsingConfig` object must be configured.
 //! It controls which features are enabled and how certain edge cases should be handled.
 //! It is also used to add flags, parameters, subcommands and specify which parameters are required and other extra info.
+//! Alternatively, it can be built through chained method calls, starting with `ParsingConfig::builder`.
 //! Once configured, it can be used with the `parse` function.
 //!
 //! A flag is an option that has a name and does not require an argument.
@@ -20,13 +21,50 @@
 //! All option names and aliases can contain only lower and uppercase letters and hyphens.
 //!
 //! Note that the first argument is always interpreted as the name of the command (or subcommand) which was invoked.
+//!
+//! If the `serde` feature is enabled, `ParsedArgs`, `ParsingError`, `Event` and `ParsingConfig` implement `Serialize` and `Deserialize`.
+//! A deserialized `ParsingConfig` interprets argument lists exactly like the one that was serialized.
+//! It is checked like the methods that configure it, so deserializing an invalid `ParsingConfig` fails with a `ConfigError`.
+
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
 mod parsing_error;
+mod parsing_warning;
+mod config_error;
 mod parsing_config;
+#[cfg(feature = "serde")]
+mod config_desc;
+mod config_builder;
+mod lint;
 mod parsed_args;
+mod canonical;
+mod occurrence;
+mod event;
+mod lazy_args;
 mod parse;
+mod tokenize;
+mod complete;
+mod complete_env;
+mod usage;
+mod prompt;
+mod external;
 
 pub use self::parsing_error::*;
+pub use self::parsing_warning::*;
+pub use self::config_error::*;
 pub use self::parsing_config::*;
+pub use self::config_builder::*;
+pub use self::lint::*;
 pub use self::parsed_args::*;
+pub use self::occurrence::*;
+pub use self::event::*;
+pub use self::lazy_args::*;
 pub use self::parse::*;
+pub use self::tokenize::*;
+pub use self::complete::*;
+pub use self::complete_env::*;
+pub use self::usage::*;
+pub use self::prompt::*;
+pub use self::external::*;
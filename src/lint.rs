@@ -0,0 +1,156 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Error as FmtError;
+
+use occurrence::Syntax;
+use parsing_config::ArgDesc;
+use parsing_config::ParsingConfig;
+use parsing_config::SingleHyphenMode;
+
+
+/// Describes a problem in a `ParsingConfig` that does not prevent parsing but likely does not behave as intended.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConfigLint {
+    /// Indicates that the name of an option is a prefix of the name of another option with a different target, while option completion is enabled.
+    /// The shorter option can only be specified by its full name, and every abbreviation of it is ambiguous.
+    ///
+    /// The first associated string is the shorter name.
+    /// The second associated string is the longer name.
+    PrefixOption(String, String),
+
+    /// Indicates that an option has a name of more than one character, while no enabled syntax can specify such a name.
+    ///
+    /// The associated string is the name of the aforementioned option, which can be an alias.
+    UnreachableOption(String),
+
+    /// Indicates that an alias has a name of more than one character, while an enabled syntax that is meant for names cannot specify such a name.
+    /// The alias can still be specified with another syntax.
+    ///
+    /// The associated string is the name of the aforementioned alias.
+    /// The associated syntax is the syntax that cannot specify it.
+    UnreachableAlias(String, Syntax),
+
+    /// Indicates that two names of more than one character differ only in case, while case-insensitive matching is enabled.
+    /// Only exact matches can tell them apart, every other spelling is ambiguous.
+    ///
+    /// The associated strings are the two names, in order.
+    CaseConflict(String, String),
+
+    /// Indicates that subcommand completion is enabled without the subcommand index, so it never occurs.
+    SubcommandCompletionWithoutIndex,
+
+    /// Indicates that a subcommand is required while no subcommands were added.
+    SubcommandRequiredWithoutSubcommands,
+}
+
+impl Display for ConfigLint {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self {
+            ConfigLint::PrefixOption(name, other) => write!(f, "option '{}' is a prefix of option '{}'", name, other),
+            ConfigLint::UnreachableOption(name) => write!(f, "option '{}' cannot be specified with any enabled syntax", name),
+            ConfigLint::UnreachableAlias(name, syntax) => write!(f, "alias '{}' cannot be specified with {} syntax", name, syntax_name(*syntax)),
+            ConfigLint::CaseConflict(name, other) => write!(f, "names '{}' and '{}' differ only in case", name, other),
+            ConfigLint::SubcommandCompletionWithoutIndex => write!(f, "subcommand completion is enabled without the subcommand index"),
+            ConfigLint::SubcommandRequiredWithoutSubcommands => write!(f, "a subcommand is required but there are no subcommands"),
+        }
+    }
+}
+
+
+/// Returns the name of the `syntax` as it is used in diagnostics.
+fn syntax_name(syntax: Syntax) -> &'static str {
+    match syntax {
+        Syntax::DoubleHyphenAssignment => "double hyphen assignment",
+        Syntax::DoubleHyphen => "double hyphen",
+        Syntax::SingleHyphen => "single hyphen",
+        Syntax::Plus => "plus",
+        Syntax::Slash => "slash",
+        Syntax::Assignment => "assignment",
+    }
+}
+
+
+impl ParsingConfig {
+    /// Returns `true` if an option with a name of more than one character can be specified.
+    /// Flags cannot be specified through syntaxes that always assign a value.
+    fn long_name_reachable(&self, flag: bool) -> bool {
+        self.dh_syntax
+            || self.slash_syntax
+            || (self.sh_syntax && self.sh_mode != SingleHyphenMode::Clustered)
+            || (!flag && (self.dha_syntax || self.a_syntax))
+    }
+
+    /// Returns the enabled syntaxes that are meant for names of more than one character but cannot specify them.
+    /// Plus syntax is left out because it only exists for names of one character, and so are the syntaxes that always assign a value if the option is a flag.
+    fn long_name_blocked(&self) -> Vec<Syntax> {
+        let mut syntaxes = Vec::new();
+        if self.sh_syntax && self.sh_mode == SingleHyphenMode::Clustered {
+            syntaxes.push(Syntax::SingleHyphen);
+        }
+        syntaxes
+    }
+
+    /// Returns the name of the option that the `name` points to, which is the `name` itself unless it is an alias.
+    fn lint_target<'a>(&'a self, name: &'a str) -> &'a str {
+        match self.options.get(name) {
+            Some(ArgDesc::Alias(target)) => target,
+            _ => name,
+        }
+    }
+
+    /// Checks the configuration for problems that do not prevent parsing but likely do not behave as intended.
+    ///
+    /// Returns an empty list if no problems were found.
+    /// The diagnostics are ordered by kind, and by name within each kind.
+    pub fn lint(&self) -> Vec<ConfigLint> {
+        let mut lints = Vec::new();
+        let mut names: Vec<&String> = self.options.keys().collect();
+        names.sort_unstable();
+
+        if self.option_completion {
+            for name in names.iter().filter(|x| x.chars().count() > 1) {
+                let target = self.lint_target(name);
+                for other in names.iter().filter(|x| *x != name && x.starts_with(name.as_str()) && self.lint_target(x) != target) {
+                    lints.push(ConfigLint::PrefixOption(name.to_string(), other.to_string()));
+                }
+            }
+        }
+
+        let mut reachable_aliases = Vec::new();
+        for name in names.iter().filter(|x| x.chars().count() > 1) {
+            let flag = self.options.get(self.lint_target(name)) == Some(&ArgDesc::Flag);
+            if !self.long_name_reachable(flag) {
+                lints.push(ConfigLint::UnreachableOption(name.to_string()));
+            } else if let Some(ArgDesc::Alias(_)) = self.options.get(*name) {
+                reachable_aliases.push(name);
+            }
+        }
+        for name in reachable_aliases {
+            for syntax in self.long_name_blocked() {
+                lints.push(ConfigLint::UnreachableAlias(name.to_string(), syntax));
+            }
+        }
+
+        if self.case_insensitive {
+            let mut all_names: Vec<&String> = self.options.keys().chain(self.subcmds.iter()).chain(self.subcmd_aliases.keys()).collect();
+            all_names.sort_unstable();
+            for (i, name) in all_names.iter().enumerate().filter(|x| x.1.chars().count() > 1) {
+                let lowercase = name.to_lowercase();
+                for other in all_names[i + 1..].iter().filter(|x| x.to_lowercase() == lowercase) {
+                    lints.push(ConfigLint::CaseConflict(name.to_string(), other.to_string()));
+                }
+            }
+        }
+
+        if self.subcmd_completion && !self.subcmd_index {
+            lints.push(ConfigLint::SubcommandCompletionWithoutIndex);
+        }
+
+        if self.subcmd_required && self.subcmds.is_empty() {
+            lints.push(ConfigLint::SubcommandRequiredWithoutSubcommands);
+        }
+
+        lints
+    }
+}
@@ -0,0 +1,56 @@
+/// Identifies the syntax through which an option was specified.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Syntax {
+    /// The double hyphen assignment syntax, as in `--NAME=VALUE`.
+    DoubleHyphenAssignment,
+
+    /// The double hyphen syntax, as in `--NAME`.
+    DoubleHyphen,
+
+    /// The single hyphen syntax, as in `-N` or `-NAME`.
+    SingleHyphen,
+
+    /// The plus syntax, as in `+N`.
+    Plus,
+
+    /// The slash syntax, as in `/NAME`.
+    Slash,
+
+    /// The assignment syntax, as in `NAME=VALUE`.
+    Assignment,
+}
+
+
+/// Describes where and how a flag, parameter or unnamed parameter occurred in the argument list.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Occurrence {
+    pub(crate) index: usize,
+    pub(crate) typed: String,
+    pub(crate) syntax: Option<Syntax>,
+}
+
+impl Occurrence {
+    /// Returns the index in the argument list of the argument that contained the occurrence.
+    ///
+    /// The first argument, the name of the command, has index zero.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns what the user actually typed to specify the occurrence.
+    ///
+    /// For options this is the name without the syntax around it, which can be an alias or an abbreviation of the option's name.
+    /// For unnamed parameters this is empty, since the argument itself is returned by `ParsedArgs::unnamed_params` at the same position.
+    pub fn typed(&self) -> &str {
+        self.typed.as_str()
+    }
+
+    /// Returns the syntax through which the option was specified.
+    ///
+    /// Returns `None` for unnamed parameters.
+    pub fn syntax(&self) -> Option<Syntax> {
+        self.syntax
+    }
+}
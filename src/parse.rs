@@ -1,41 +1,106 @@
-use std::collections::LinkedList;
-use std::collections::HashMap;
 use std::collections::HashSet;
-use std::collections::hash_map::Entry;
+use std::collections::VecDeque;
 
+use event::Event;
+use event::EventKind;
+use external::ExternalSubcommand;
+use external::ExternalSubcommands;
+use occurrence::Syntax;
 use parsing_error::ParsingError;
 use parsing_config::ArgDesc;
 use parsing_config::ParsingConfig;
+use parsing_config::SingleHyphenMode;
 use parsed_args::ParsedArgs;
+use lazy_args::LazyArgs;
 
 
+pub(crate) fn names_equal(config: &ParsingConfig, name: &str, other: &str) -> bool {
+    name == other || (config.case_insensitive && name.to_lowercase() == other.to_lowercase())
+}
+
+pub(crate) fn name_starts_with(config: &ParsingConfig, name: &str, prefix: &str) -> bool {
+    name.starts_with(prefix) || (config.case_insensitive && name.to_lowercase().starts_with(&prefix.to_lowercase()))
+}
+
 fn match_option<'a>(config: &'a ParsingConfig, name: &str) -> Vec<(&'a String, &'a ArgDesc)> {
+    let completes = name.chars().count() >= config.completion_min_length;
+    let mut exact_matches = Vec::new();
     let mut matches = Vec::new();
     for value in config.options.iter() {
         if value.0 == name {
             return vec![value];
-        } else if value.0.starts_with(name) {
+        } else if names_equal(config, value.0, name) {
+            exact_matches.push(value);
+        } else if completes && !config.no_completion.contains(value.0) && !config.hidden.contains(value.0) && name_starts_with(config, value.0, name) {
             matches.push(value);
         }
     }
+    if !exact_matches.is_empty() {
+        matches = exact_matches;
+    }
     matches.sort_unstable();
+
+    // Aliases are dropped in favour of their target, or of the first alias to the same target.
+    let mut targets: HashSet<&str> = matches.iter()
+        .filter(|x| !matches!(x.1, ArgDesc::Alias(_)))
+        .map(|x| x.0.as_str())
+        .collect();
+    matches.retain(|value| match value.1 {
+        ArgDesc::Alias(target) => targets.insert(target.as_str()),
+        _ => true,
+    });
     matches
 }
 
-fn match_subcmd<'a>(config: &'a ParsingConfig, name: &str) -> Vec<&'a String> {
+pub(crate) fn match_subcmd<'a>(config: &'a ParsingConfig, name: &str) -> Vec<&'a String> {
+    let mut exact_matches = Vec::new();
     let mut matches = Vec::new();
-    for value in config.subcmds.iter() {
+    for value in config.subcmds.iter().chain(config.subcmd_aliases.keys()) {
         if value == name {
             return vec![value];
-        } else if value.starts_with(name) {
+        } else if names_equal(config, value, name) {
+            exact_matches.push(value);
+        } else if name_starts_with(config, value, name) {
             matches.push(value);
         }
     }
+    if !exact_matches.is_empty() {
+        matches = exact_matches;
+    }
     matches.sort_unstable();
+
+    // Aliases are dropped in favour of their subcommand, or of the first alias to the same subcommand.
+    let mut targets: HashSet<&str> = matches.iter()
+        .filter(|x| config.subcmds.contains(x.as_str()))
+        .map(|x| x.as_str())
+        .collect();
+    matches.retain(|value| match config.subcmd_aliases.get(value.as_str()) {
+        Some(target) => targets.insert(target.as_str()),
+        None => true,
+    });
     matches
 }
 
-fn parse_option_name<'a>(config: &'a ParsingConfig, name: &'a str) -> Result<(&'a str, &'a ArgDesc), ParsingError> {
+fn get_option<'a>(config: &'a ParsingConfig, name: &str) -> Option<(&'a String, &'a ArgDesc)> {
+    match config.options.get_key_value(name) {
+        Some(value) => Some(value),
+        None => config.options.iter().filter(|x| names_equal(config, x.0, name)).min(),
+    }
+}
+
+pub(crate) fn get_subcmd<'a>(config: &'a ParsingConfig, name: &str) -> Option<&'a String> {
+    match config.subcmds.get(name).or_else(|| config.subcmd_aliases.get_key_value(name).map(|x| x.0)) {
+        Some(value) => Some(value),
+        None => config.subcmds.iter().chain(config.subcmd_aliases.keys()).filter(|x| names_equal(config, x, name)).min(),
+    }
+}
+
+fn ambiguous_subcmd(config: &ParsingConfig, name: String, matches: &[&String]) -> ParsingError {
+    let matches = matches.iter().map(|x| config.subcmd_aliases.get(x.as_str()).unwrap_or(x).to_string()).collect();
+    ParsingError::AmbiguousSubcommand(name, matches)
+}
+
+pub(crate) fn parse_option_name<'a>(config: &'a ParsingConfig, name: &str) -> Result<(&'a str, &'a ArgDesc), ParsingError> {
     if config.option_completion {
         let matches = match_option(config, name);
         match matches.len() {
@@ -44,355 +109,751 @@ fn parse_option_name<'a>(config: &'a ParsingConfig, name: &'a str) -> Result<(&'
             _ => Err(ParsingError::AmbiguousOption(name.to_string(), matches.iter().map(|x| x.0.to_string()).collect())),
         }
     } else {
-        match config.options.get(name) {
-            Some(desc) => Ok((name, desc)),
+        match get_option(config, name) {
+            Some((name, desc)) => Ok((name.as_str(), desc)),
             None => Err(ParsingError::UnrecognizedOption(name.to_string())),
         }
     }
 }
 
-fn insert_param(params: &mut HashMap<String, String>, config: &ParsingConfig, target: &str, name: &str, value: String, aliased: bool) -> Result<(), ParsingError> {
-    match params.entry(target.to_string()) {
-        Entry::Vacant(spot) => {
-            spot.insert(value);
-            Ok(())
-        },
-        Entry::Occupied(mut spot) => if config.param_duplication {
-            spot.insert(value);
-            Ok(())
-        } else {
-            if aliased {
-                Err(ParsingError::ParameterDuplicationAlias(target.to_string(), name.to_string()))
-            } else {
-                Err(ParsingError::ParameterDuplication(target.to_string()))
-            }
-        }
-    }
-}
-
-fn resolve_target<'a>(config: &ParsingConfig, name: &'a str, desc: &'a ArgDesc) -> (&'a str, bool, bool) {
+pub(crate) fn resolve_target<'a>(config: &ParsingConfig, name: &'a str, desc: &'a ArgDesc) -> (&'a str, bool, bool) {
     match desc {
         ArgDesc::Flag => (name, false, true),
-        ArgDesc::Param(_) => (name, false, false),
+        ArgDesc::Param(_) | ArgDesc::OptionalParam(_) => (name, false, false),
         ArgDesc::Alias(alias_target) => match config.options.get(alias_target).unwrap() {
             ArgDesc::Flag => (alias_target.as_str(), true, true),
-            ArgDesc::Param(_) => (alias_target.as_str(), true, false),
+            ArgDesc::Param(_) | ArgDesc::OptionalParam(_) => (alias_target.as_str(), true, false),
             ArgDesc::Alias(_) => panic!(),
         },
     }
 }
 
+fn implicit_value<'a>(config: &'a ParsingConfig, target: &str) -> Option<&'a str> {
+    match config.options.get(target) {
+        Some(ArgDesc::OptionalParam(value)) => Some(value.as_str()),
+        _ => None,
+    }
+}
 
-/// Parses the argument list according to the provided configuration.
-/// The result is either an error or the parsed arguments.
-///
-/// Note that the argument list passed to this function cannot contain non-unicode characters.
-pub fn parse<I: Iterator<Item=String>>(mut args: I, config: &ParsingConfig) -> Result<ParsedArgs, ParsingError> {
-    let name = args.next().unwrap_or(String::new());
-    let mut flags = HashSet::new();
-    let mut params = HashMap::new();
-    let mut unnameds = Vec::new();
-    let mut subcommand = Vec::new();
-
-    while let Some(arg) = args.next() {
-        if config.dh_marker && arg == "--" {
-            if config.store_dh_marker {
-                unnameds.push(arg);
-            }
-            for arg in args {
-                unnameds.push(arg);
-            }
-            break;
-        }
+/// Returns the choice that the `value` matches if the parameter has choices, or the `value` itself otherwise.
+pub(crate) fn check_choice(config: &ParsingConfig, target: &str, value: String) -> Result<String, ParsingError> {
+    match config.choices.get(target) {
+        Some(choices) => match choices.iter().find(|x| names_equal(config, x, &value)) {
+            Some(choice) => Ok(choice.to_string()),
+            None => Err(ParsingError::InvalidChoice(target.to_string(), value, choices.clone())),
+        },
+        None => Ok(value),
+    }
+}
 
+fn assignment_to_flag(target: &str, name: &str, aliased: bool) -> ParsingError {
+    if aliased {
+        ParsingError::AssignmentToFlagAlias(target.to_string(), name.to_string())
+    } else {
+        ParsingError::AssignmentToFlag(target.to_string())
+    }
+}
 
-        let eq_index = arg.find('=');
+fn missing_argument(target: &str, name: &str, aliased: bool) -> ParsingError {
+    if aliased {
+        ParsingError::MissingArgumentAlias(target.to_string(), name.to_string())
+    } else {
+        ParsingError::MissingArgument(target.to_string())
+    }
+}
 
-        if config.dha_syntax && arg.starts_with("--") && eq_index != None {
-            let eq_index = eq_index.unwrap();
-            let opt_name = &arg[2..eq_index];
-            let value = &arg[eq_index+1..];
-            let (name, desc) = parse_option_name(config, opt_name)?;
-            let (target, aliased, flag) = resolve_target(config, name, desc);
 
-            if flag {
-                if aliased {
-                    return Err(ParsingError::AssignmentToFlagAlias(target.to_string(), name.to_string()));
-                } else {
-                    return Err(ParsingError::AssignmentToFlag(target.to_string()));
-                }
-            } else {
-                insert_param(&mut params, config, target, name, value.to_string(), aliased)?;
-                continue;
-            }
+/// Interprets an argument list according to a configuration and produces the resulting events in order.
+pub(crate) struct Parser<'a, I> {
+    config: &'a ParsingConfig,
+    args: I,
+    pub(crate) name: String,
+    index: usize,
+    unnamed_count: usize,
+    set_params: HashSet<String>,
+    syntax: Option<Syntax>,
+    marker_found: bool,
+    subcommand_found: bool,
+    finished: bool,
+    pub(crate) passthrough: bool,
+    pub(crate) externals: Option<&'a ExternalSubcommands>,
+    pub(crate) leave_rest: bool,
+    pub(crate) defer_globals: bool,
+    pub(crate) deferred_globals: Vec<String>,
+    rest_left: bool,
+    pending: VecDeque<Event>,
+}
+
+impl<'a, I: Iterator<Item=String>> Parser<'a, I> {
+    pub(crate) fn new(mut args: I, config: &'a ParsingConfig) -> Self {
+        let name = args.next().unwrap_or_default();
+        Self {
+            config,
+            args,
+            name,
+            index: 0,
+            unnamed_count: 0,
+            set_params: HashSet::new(),
+            syntax: None,
+            marker_found: false,
+            subcommand_found: false,
+            finished: false,
+            passthrough: config.unknown_passthrough,
+            externals: None,
+            leave_rest: false,
+            defer_globals: false,
+            deferred_globals: Vec::new(),
+            rest_left: false,
+            pending: VecDeque::new(),
         }
+    }
 
-        if config.dh_syntax && arg.starts_with("--") {
-            let opt_name = &arg[2..];
-            let (name, desc) = parse_option_name(config, opt_name)?;
-            let (target, aliased, flag) = resolve_target(config, name, desc);
+    /// Consumes the underlying argument list and returns it with the arguments that have not been interpreted yet.
+    pub(crate) fn into_args(self) -> I {
+        self.args
+    }
 
-            if flag {
-                flags.insert(target.to_string());
+    fn next_arg(&mut self) -> Option<String> {
+        let arg = self.args.next();
+        if arg.is_some() {
+            self.index += 1;
+        }
+        arg
+    }
+
+    fn push(&mut self, kind: EventKind, index: usize) {
+        self.pending.push_back(Event {
+            kind,
+            index,
+            alias: None,
+            typed: None,
+            syntax: None,
+            implicit: false,
+        });
+    }
+
+    fn push_option(&mut self, kind: EventKind, index: usize, name: &str, typed: &str, aliased: bool) {
+        let alias = if aliased {
+            Some(name.to_string())
+        } else {
+            None
+        };
+        self.pending.push_back(Event {
+            kind,
+            index,
+            alias,
+            typed: Some(typed.to_string()),
+            syntax: self.syntax,
+            implicit: false,
+        });
+    }
+
+    fn push_flag(&mut self, target: &str, name: &str, typed: &str, aliased: bool, index: usize, on: bool) {
+        let kind = if on {
+            EventKind::Flag(target.to_string())
+        } else {
+            EventKind::FlagOff(target.to_string())
+        };
+        self.push_option(kind, index, name, typed, aliased);
+    }
+
+    /// Pushes the `value` for a parameter, or its implicit value if the `value` is `None`.
+    fn push_param(&mut self, target: &str, name: &str, typed: &str, value: Option<String>, aliased: bool, index: usize) -> Result<(), ParsingError> {
+        // The implicit value is chosen by the program, so it is not checked against the choices.
+        let (value, implicit) = match value {
+            Some(value) => (check_choice(self.config, target, value)?, false),
+            None => (implicit_value(self.config, target).unwrap_or_default().to_string(), true),
+        };
+        if !self.set_params.insert(target.to_string()) && !self.config.param_duplication {
+            if aliased {
+                return Err(ParsingError::ParameterDuplicationAlias(target.to_string(), name.to_string()));
             } else {
-                let value = match args.next() {
-                    Some(arg) => arg,
-                    None => if aliased {
-                        return Err(ParsingError::MissingArgumentAlias(target.to_string(), name.to_string()));
-                    } else {
-                        return Err(ParsingError::MissingArgument(target.to_string()));
-                    },
-                };
-                insert_param(&mut params, config, target, name, value, aliased)?;
+                return Err(ParsingError::ParameterDuplication(target.to_string()));
             }
-            continue;
         }
+        self.push_option(EventKind::Param(target.to_string(), value), index, name, typed, aliased);
+        if let Some(event) = self.pending.back_mut() {
+            event.implicit = implicit;
+        }
+        Ok(())
+    }
 
-        if config.sh_syntax && arg.starts_with('-') {
-            let mut opts = Vec::new();
-            let mut value = String::new();
-            let mut iter = arg.chars().skip(1);
-            while let Some(arg_char) = iter.next() {
-                if arg_char.is_alphabetic() {
-                    opts.push(arg_char);
-                } else {
-                    value.push(arg_char);
-                    while let Some(arg_char) = iter.next() {
-                        value.push(arg_char);
-                    }
-                }
-            }
+    fn push_unnamed(&mut self, arg: String, index: usize) {
+        self.unnamed_count += 1;
+        self.push(EventKind::Unnamed(arg), index);
+    }
 
-            if opts.len() == 0 {
-                let desc = match config.options.get("") {
-                    Some(desc) => desc,
-                    None => if value.is_empty() {
-                        unnameds.push(String::from("-"));
-                        continue;
-                    } else {
-                        return Err(ParsingError::UnrecognizedOption(value));
-                    },
-                };
-                let (target, aliased, flag) = resolve_target(config, "", desc);
+    fn push_subcommand(&mut self, name: &str, index: usize) {
+        let (target, alias) = match self.config.subcmd_aliases.get(name) {
+            Some(target) => (target.to_string(), Some(name.to_string())),
+            None => (name.to_string(), None),
+        };
+        let rest = self.take_rest();
+        self.pending.push_back(Event {
+            kind: EventKind::Subcommand(target, rest),
+            index,
+            alias,
+            typed: None,
+            syntax: None,
+            implicit: false,
+        });
+    }
 
-                if flag {
-                    if value.is_empty() {
-                        flags.insert(target.to_string());
-                    } else if aliased {
-                        return Err(ParsingError::AssignmentToFlagAlias(target.to_string(), String::new()));
-                    } else {
-                        return Err(ParsingError::AssignmentToFlag(target.to_string()));
-                    }
-                } else if value.is_empty() {
-                    if aliased {
-                        return Err(ParsingError::MissingArgumentAlias(target.to_string(), String::new()));
-                    } else {
-                        return Err(ParsingError::MissingArgument(target.to_string()));
-                    }
-                } else {
-                    insert_param(&mut params, config, target, "", value, aliased)?;
-                }
-                continue;
-            }
+    /// Returns the arguments that follow a subcommand, after which parsing ends.
+    /// If `leave_rest` is set, the arguments are left in the argument list instead and an empty list is returned.
+    fn take_rest(&mut self) -> Vec<String> {
+        self.subcommand_found = true;
+        if self.leave_rest {
+            self.rest_left = true;
+            return Vec::new();
+        }
+        let mut rest = Vec::new();
+        while let Some(arg) = self.next_arg() {
+            rest.push(arg);
+        }
+        rest
+    }
 
-            let mut last_was_flag = false;
-            let mut param_opts = Vec::new();
-            let mut last_flag = (String::new(), false, String::new());
-            for opt in opts {
-                let name = opt.to_string();
-                let desc = match config.options.get(&name) {
-                    Some(desc) => desc,
-                    None => return Err(ParsingError::UnrecognizedOption(name)),
-                };
-                let (target, aliased, flag) = resolve_target(config, &name, desc);
+    /// Looks for an executable that implements the subcommand with the `name`, and pushes it if one is found.
+    /// Returns `true` if the subcommand was found.
+    fn push_external(&mut self, name: &str, index: usize) -> bool {
+        let path = match self.externals.and_then(|x| x.find(name)) {
+            Some(path) => path,
+            None => return false,
+        };
+        let args = self.take_rest();
+        self.push(EventKind::External(ExternalSubcommand { name: name.to_string(), path, args }), index);
+        true
+    }
 
-                if flag {
-                    last_was_flag = true;
-                    flags.insert(target.to_string());
-                    last_flag = (target.to_string(), aliased, name.clone());
-                } else {
-                    last_was_flag = false;
-                    param_opts.push((target.to_string(), aliased, name.clone()));
-                }
+    /// Returns the next argument as the value for a parameter, or `None` if the parameter gets its implicit value instead.
+    fn take_value(&mut self, target: &str, name: &str, aliased: bool) -> Result<Option<String>, ParsingError> {
+        match implicit_value(self.config, target) {
+            Some(_) => Ok(None),
+            None => match self.next_arg() {
+                Some(arg) => Ok(Some(arg)),
+                None => Err(missing_argument(target, name, aliased)),
+            },
+        }
+    }
+
+    fn parse_named(&mut self, name: &str, typed: &str, desc: &ArgDesc, value: Option<&str>, index: usize) -> Result<(), ParsingError> {
+        let (target, aliased, flag) = resolve_target(self.config, name, desc);
+
+        if flag {
+            if value.is_some() {
+                return Err(assignment_to_flag(target, name, aliased));
             }
+            self.push_flag(target, name, typed, aliased, index, true);
+        } else {
+            let value = match value {
+                Some(value) => Some(value.to_string()),
+                None => self.take_value(target, name, aliased)?,
+            };
+            self.push_param(target, name, typed, value, aliased, index)?;
+        }
+        Ok(())
+    }
 
-            if config.param_stacking && !param_opts.is_empty() {
-                if last_was_flag && !value.is_empty() {
-                    let (target, aliased, name) = last_flag;
-                    if aliased {
-                        return Err(ParsingError::AssignmentToFlagAlias(target, name));
-                    } else {
-                        return Err(ParsingError::AssignmentToFlag(target));
-                    }
+    /// Interprets an argument in the long single hyphen form as a parameter followed by its value, as in "-NAME495".
+    /// The value starts at the first character that cannot be part of a name.
+    /// The `error_name` is reported as unrecognized if the argument does not have that form.
+    fn parse_attached(&mut self, arg: &str, error_name: String, index: usize) -> Result<(), ParsingError> {
+        if let Some(value_index) = arg.find(|x: char| !x.is_alphabetic() && x != '-') {
+            let opt_name = &arg[..value_index];
+            if let Ok((name, desc)) = parse_option_name(self.config, opt_name) {
+                if !resolve_target(self.config, name, desc).2 {
+                    return self.parse_named(name, opt_name, desc, Some(&arg[value_index..]), index);
                 }
+            }
+        }
+        Err(ParsingError::UnrecognizedOption(error_name))
+    }
 
-                let mut param_vals = LinkedList::new();
-                for i in 0..param_opts.len()-1 {
-                    param_vals.push_back(match args.next() {
-                        Some(arg) => arg,
-                        None => {
-                            param_opts.resize(i + 1, (String::new(), false, String::new()));
-                            let (target, aliased, name) = param_opts.pop().unwrap();
-                            if aliased {
-                                return Err(ParsingError::MissingArgumentAlias(target, name));
-                            } else {
-                                return Err(ParsingError::MissingArgument(target));
-                            }
-                        },
-                    });
+    fn parse_cluster(&mut self, arg: &str, index: usize) -> Result<(), ParsingError> {
+        let config = self.config;
+        let mut opts = Vec::new();
+        let mut value = String::new();
+        let mut iter = arg.chars().skip(1);
+        while let Some(arg_char) = iter.next() {
+            if arg_char.is_alphabetic() {
+                opts.push(arg_char);
+            } else {
+                value.push(arg_char);
+                for arg_char in iter.by_ref() {
+                    value.push(arg_char);
                 }
+            }
+        }
 
-                if value.is_empty() {
-                    param_vals.push_back(match args.next() {
-                        Some(arg) => arg,
-                        None => {
-                            let (target, aliased, name) = param_opts.pop().unwrap();
-                            if aliased {
-                                return Err(ParsingError::MissingArgumentAlias(target, name));
-                            } else {
-                                return Err(ParsingError::MissingArgument(target));
-                            }
-                        },
-                    });
+        if opts.is_empty() {
+            let desc = match config.options.get("") {
+                Some(desc) => desc,
+                None => if value.is_empty() {
+                    self.push_unnamed(String::from("-"), index);
+                    return Ok(());
                 } else {
-                    param_vals.push_back(value);
-                }
-
-                for i in 0..param_opts.len() {
-                    insert_param(&mut params, config, param_opts[i].0.as_str(), param_opts[i].2.as_str(), param_vals.pop_front().unwrap(), param_opts[i].1)?;
-                }
-            } else if !param_opts.is_empty() {
-                if last_was_flag || param_opts.len() > 1 {
-                    param_opts.resize(1, (String::new(), false, String::new()));
-                    let (target, aliased, name) = param_opts.pop().unwrap();
-                    if aliased {
-                        return Err(ParsingError::MissingArgumentAlias(target, name));
-                    } else {
-                        return Err(ParsingError::MissingArgument(target));
-                    }
-                }
+                    return Err(ParsingError::UnrecognizedOption(value));
+                },
+            };
+            let (target, aliased, flag) = resolve_target(config, "", desc);
 
-                let (target, aliased, name) = param_opts.pop().unwrap();
+            if flag {
                 if value.is_empty() {
-                    value = match args.next() {
-                        Some(arg) => arg,
-                        None => {
-                            if aliased {
-                                return Err(ParsingError::MissingArgumentAlias(target, name));
-                            } else {
-                                return Err(ParsingError::MissingArgument(target));
-                            }
-                        },
-                    };
-                }
-                insert_param(&mut params, config, &target, &name, value, aliased)?;
-            } else if !value.is_empty() {
-                let (target, aliased, name) = last_flag;
-                if aliased {
-                    return Err(ParsingError::AssignmentToFlagAlias(target, name));
+                    self.push_flag(target, "", "", aliased, index, true);
                 } else {
-                    return Err(ParsingError::AssignmentToFlag(target));
+                    return Err(assignment_to_flag(target, "", aliased));
                 }
+            } else if value.is_empty() {
+                match implicit_value(config, target) {
+                    Some(_) => self.push_param(target, "", "", None, aliased, index)?,
+                    None => return Err(missing_argument(target, "", aliased)),
+                }
+            } else {
+                self.push_param(target, "", "", Some(value), aliased, index)?;
             }
-            continue;
+            return Ok(());
         }
 
-        if config.a_syntax && eq_index != None {
-            let eq_index = eq_index.unwrap();
-            let opt_name = &arg[..eq_index];
-            let value = &arg[eq_index+1..];
-            let (name, desc) = parse_option_name(config, opt_name)?;
-            let (target, aliased, flag) = resolve_target(config, name, desc);
+        let mut last_was_flag = false;
+        let mut last_was_optional = false;
+        let mut param_opts = Vec::new();
+        let mut optional_opts = Vec::new();
+        let mut last_flag = (String::new(), false, String::new());
+        for opt in opts {
+            let name = opt.to_string();
+            let desc = match config.options.get(&name) {
+                Some(desc) => desc,
+                None => return Err(ParsingError::UnrecognizedOption(name)),
+            };
+            let (target, aliased, flag) = resolve_target(config, &name, desc);
 
             if flag {
-                if aliased {
-                    return Err(ParsingError::AssignmentToFlagAlias(target.to_string(), name.to_string()));
-                } else {
-                    return Err(ParsingError::AssignmentToFlag(target.to_string()));
-                }
+                last_was_flag = true;
+                last_was_optional = false;
+                self.push_flag(target, &name, &name, aliased, index, true);
+                last_flag = (target.to_string(), aliased, name.clone());
+            } else if implicit_value(config, target).is_some() {
+                last_was_flag = true;
+                last_was_optional = true;
+                optional_opts.push((target.to_string(), aliased, name.clone()));
+            } else {
+                last_was_flag = false;
+                last_was_optional = false;
+                param_opts.push((target.to_string(), aliased, name.clone()));
+            }
+        }
+
+        if last_was_optional && !value.is_empty() {
+            let (target, aliased, name) = optional_opts.pop().unwrap();
+            self.push_param(&target, &name, &name, Some(value), aliased, index)?;
+            value = String::new();
+        }
+        for (target, aliased, name) in optional_opts {
+            self.push_param(&target, &name, &name, None, aliased, index)?;
+        }
+
+        if config.param_stacking && !param_opts.is_empty() {
+            if last_was_flag && !value.is_empty() {
+                let (target, aliased, name) = last_flag;
+                return Err(assignment_to_flag(&target, &name, aliased));
+            }
+
+            let mut param_vals = Vec::with_capacity(param_opts.len());
+            for (target, aliased, name) in param_opts.iter().take(param_opts.len()-1) {
+                param_vals.push(match self.next_arg() {
+                    Some(arg) => arg,
+                    None => return Err(missing_argument(target, name, *aliased)),
+                });
+            }
+
+            if value.is_empty() {
+                param_vals.push(match self.next_arg() {
+                    Some(arg) => arg,
+                    None => {
+                        let (target, aliased, name) = param_opts.pop().unwrap();
+                        return Err(missing_argument(&target, &name, aliased));
+                    },
+                });
             } else {
-                insert_param(&mut params, config, target, name, value.to_string(), aliased)?;
-                continue;
+                param_vals.push(value);
+            }
+
+            for ((target, aliased, name), value) in param_opts.iter().zip(param_vals) {
+                self.push_param(target, name, name, Some(value), *aliased, index)?;
+            }
+        } else if !param_opts.is_empty() {
+            if last_was_flag || param_opts.len() > 1 {
+                let (target, aliased, name) = &param_opts[0];
+                return Err(missing_argument(target, name, *aliased));
             }
+
+            let (target, aliased, name) = param_opts.pop().unwrap();
+            if value.is_empty() {
+                value = match self.next_arg() {
+                    Some(arg) => arg,
+                    None => return Err(missing_argument(&target, &name, aliased)),
+                };
+            }
+            self.push_param(&target, &name, &name, Some(value), aliased, index)?;
+        } else if !value.is_empty() {
+            let (target, aliased, name) = last_flag;
+            return Err(assignment_to_flag(&target, &name, aliased));
         }
+        Ok(())
+    }
 
+    fn parse_unnamed(&mut self, arg: String, index: usize) -> Result<(), ParsingError> {
+        let config = self.config;
 
         if config.subcmd_required && config.subcmd_index {
-            if unnameds.len() == config.subcmd_index_value {
+            if self.unnamed_count == config.subcmd_index_value {
                 let name = if config.subcmd_completion {
                     let matches = match_subcmd(config, &arg);
                     match matches.len() {
+                        0 if self.push_external(&arg, index) => return Ok(()),
                         0 => return Err(ParsingError::UnrecognizedSubcommand(arg)),
                         1 => matches[0],
-                        _ => return Err(ParsingError::AmbiguousSubcommand(arg, matches.iter().map(|x| x.to_string()).collect())),
+                        _ => return Err(ambiguous_subcmd(config, arg, &matches)),
                     }
                 } else {
-                    match config.subcmds.get(&arg) {
+                    match get_subcmd(config, &arg) {
                         Some(name) => name,
+                        None if self.push_external(&arg, index) => return Ok(()),
                         None => return Err(ParsingError::UnrecognizedSubcommand(arg)),
                     }
                 };
 
-                subcommand.push(name.to_string());
-                while let Some(arg) = args.next() {
-                    subcommand.push(arg);
-                }
-                break;
+                self.push_subcommand(name, index);
+                return Ok(());
             }
         } else if config.subcmd_index {
-            if unnameds.len() == config.subcmd_index_value {
+            if self.unnamed_count == config.subcmd_index_value {
                 if config.subcmd_completion {
                     let matches = match_subcmd(config, &arg);
                     match matches.len() {
                         0 => {},
                         1 => {
-                            subcommand.push(matches[0].to_string());
-                            while let Some(arg) = args.next() {
-                                subcommand.push(arg);
-                            }
-                            break;
+                            self.push_subcommand(matches[0], index);
+                            return Ok(());
                         },
-                        _ => return Err(ParsingError::AmbiguousSubcommand(arg, matches.iter().map(|x| x.to_string()).collect())),
+                        _ => return Err(ambiguous_subcmd(config, arg, &matches)),
                     }
-                } else if let Some(name ) = config.subcmds.get(&arg) {
-                    subcommand.push(name.to_string());
-                    while let Some(arg) = args.next() {
-                        subcommand.push(arg);
+                } else if let Some(name) = get_subcmd(config, &arg) {
+                    self.push_subcommand(name, index);
+                    return Ok(());
+                }
+                if self.push_external(&arg, index) {
+                    return Ok(());
+                }
+            }
+        } else if let Some(name) = get_subcmd(config, &arg) {
+            self.push_subcommand(name, index);
+            return Ok(());
+        } else if self.unnamed_count == 0 && self.push_external(&arg, index) {
+            return Ok(());
+        }
+
+        self.push_unnamed(arg, index);
+        Ok(())
+    }
+
+    /// Interprets the next argument.
+    /// Returns `false` once there are no arguments left.
+    fn step(&mut self) -> Result<bool, ParsingError> {
+        let config = self.config;
+        if self.rest_left {
+            return Ok(false);
+        }
+        let arg = match self.next_arg() {
+            Some(arg) => arg,
+            None => return Ok(false),
+        };
+        let index = self.index;
+
+        if !self.passthrough {
+            self.parse_arg(arg, index)?;
+            return Ok(true);
+        }
+
+        let pending = self.pending.len();
+        match self.parse_arg(arg.clone(), index) {
+            Err(ParsingError::UnrecognizedOption(_)) => {
+                self.pending.truncate(pending);
+                let takes_value = config.passthrough_params.contains(arg.trim_start_matches(['-', '+', '/']));
+                self.push(EventKind::Unknown(arg), index);
+                if takes_value {
+                    if let Some(value) = self.next_arg() {
+                        let index = self.index;
+                        self.push(EventKind::Unknown(value), index);
                     }
-                    break;
                 }
+            },
+            result => result?,
+        }
+        Ok(true)
+    }
+
+    /// Interprets a single argument.
+    fn parse_arg(&mut self, arg: String, index: usize) -> Result<(), ParsingError> {
+        let config = self.config;
+
+        if self.marker_found {
+            self.push_unnamed(arg, index);
+            return Ok(());
+        }
+
+        if config.dh_marker && arg == "--" {
+            self.marker_found = true;
+            self.push(EventKind::Marker, index);
+            return Ok(());
+        }
+
+
+        let eq_index = arg.find('=');
+
+        if let Some(eq_index) = eq_index {
+            if config.dha_syntax && arg.starts_with("--") {
+                self.syntax = Some(Syntax::DoubleHyphenAssignment);
+                let opt_name = &arg[2..eq_index];
+                let value = &arg[eq_index+1..];
+                let (name, desc) = parse_option_name(config, opt_name)?;
+                self.parse_named(name, opt_name, desc, Some(value), index)?;
+                return Ok(());
             }
-        } else if let Some(_) = config.subcmds.get(&arg) {
-            subcommand.push(arg);
-            while let Some(arg) = args.next() {
-                subcommand.push(arg);
+        }
+
+        if config.dh_syntax && arg.starts_with("--") {
+            self.syntax = Some(Syntax::DoubleHyphen);
+            let opt_name = &arg[2..];
+            let (name, desc) = parse_option_name(config, opt_name)?;
+            self.parse_named(name, opt_name, desc, None, index)?;
+            return Ok(());
+        }
+
+        if config.sh_syntax && arg.starts_with('-') {
+            self.syntax = Some(Syntax::SingleHyphen);
+            if config.sh_mode != SingleHyphenMode::Clustered && arg[1..].chars().next().is_some_and(|x| x.is_alphabetic()) {
+                match parse_option_name(config, &arg[1..]) {
+                    Ok((name, desc)) => {
+                        self.parse_named(name, &arg[1..], desc, None, index)?;
+                        return Ok(());
+                    },
+                    Err(ParsingError::UnrecognizedOption(_)) if config.sh_mode == SingleHyphenMode::LongOrClustered => {},
+                    Err(ParsingError::UnrecognizedOption(opt_name)) => return self.parse_attached(&arg[1..], opt_name, index),
+                    Err(error) => return Err(error),
+                }
             }
-            break;
+
+            self.parse_cluster(&arg, index)?;
+            return Ok(());
         }
-        
-        unnameds.push(arg);
+
+        if config.plus_syntax && arg.len() > 1 && arg.starts_with('+') && arg[1..].chars().all(|x| x.is_alphabetic()) {
+            self.syntax = Some(Syntax::Plus);
+            for opt in arg[1..].chars() {
+                let name = opt.to_string();
+                let desc = match config.options.get(&name) {
+                    Some(desc) => desc,
+                    None => return Err(ParsingError::UnrecognizedOption(name)),
+                };
+                let (target, aliased, flag) = resolve_target(config, &name, desc);
+
+                if flag {
+                    self.push_flag(target, &name, &name, aliased, index, false);
+                } else if aliased {
+                    return Err(ParsingError::ToggledParameterAlias(target.to_string(), name));
+                } else {
+                    return Err(ParsingError::ToggledParameter(target.to_string()));
+                }
+            }
+            return Ok(());
+        }
+
+        if config.slash_syntax && arg.starts_with('/') {
+            let (opt_name, value) = match arg[1..].find(config.slash_separator) {
+                Some(sep_index) => (&arg[1..sep_index+1], Some(&arg[sep_index+2..])),
+                None => (&arg[1..], None),
+            };
+
+            if !opt_name.is_empty() && !opt_name.contains('/') {
+                // Names are not completed, so that an absolute path is never mistaken for an option whose name it happens to start.
+                if let Some((name, desc)) = get_option(config, opt_name) {
+                    self.syntax = Some(Syntax::Slash);
+                    self.parse_named(name, opt_name, desc, value, index)?;
+                    return Ok(());
+                }
+            }
+        }
+
+        if let Some(eq_index) = eq_index {
+            if config.a_syntax {
+                self.syntax = Some(Syntax::Assignment);
+                let opt_name = &arg[..eq_index];
+                let value = &arg[eq_index+1..];
+                let (name, desc) = parse_option_name(config, opt_name)?;
+                self.parse_named(name, opt_name, desc, Some(value), index)?;
+                return Ok(());
+            }
+        }
+
+
+        self.parse_unnamed(arg, index)?;
+        Ok(())
     }
 
-    if config.subcmd_required && subcommand.len() == 0 {
-        return Err(ParsingError::MissingRequiredSubcommand);
+    /// Performs the checks that can only be done once the whole argument list has been interpreted.
+    fn finish(&mut self) -> Result<(), ParsingError> {
+        if self.config.subcmd_required && !self.subcommand_found {
+            return Err(ParsingError::MissingRequiredSubcommand);
+        }
+        let mut required_params = Vec::new();
+        for opt in self.config.options.iter() {
+            if let (name, ArgDesc::Param(required)) = opt {
+                if *required && !self.set_params.contains(name) {
+                    // The caller passes the arguments of the subcommand on to `ParsedArgs::parse_subcommand`, which can still find it.
+                    if self.defer_globals && self.subcommand_found && self.config.globals.contains(name) {
+                        self.deferred_globals.push(name.to_string());
+                    } else {
+                        required_params.push(name.to_string());
+                    }
+                }
+            }
+        }
+        if !required_params.is_empty() {
+            return Err(ParsingError::MissingRequiredParameters(required_params));
+        }
+        Ok(())
     }
-    let mut required_params = Vec::new();
-    for opt in config.options.iter() {
-        if let (name, ArgDesc::Param(required)) = opt {
-            if *required && params.get(name) == None {
-                required_params.push(name.to_string());
+}
+
+impl<'a, I: Iterator<Item=String>> Iterator for Parser<'a, I> {
+    type Item = Result<Event, ParsingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.finished {
+                return None;
+            }
+            match self.step() {
+                Ok(true) => {},
+                Ok(false) => {
+                    self.finished = true;
+                    if let Err(error) = self.finish() {
+                        return Some(Err(error));
+                    }
+                },
+                Err(error) => {
+                    self.finished = true;
+                    self.pending.clear();
+                    return Some(Err(error));
+                },
             }
         }
     }
-    if required_params.len() != 0 {
-        return Err(ParsingError::MissingRequiredParameters(required_params));
+}
+
+/// Returns `true` if the `arg` is interpreted as an unnamed parameter when it follows `position` other unnamed parameters and is the last argument.
+pub(crate) fn parses_as_unnamed(config: &ParsingConfig, arg: &str, position: usize) -> bool {
+    let mut parser = Parser::new(std::iter::empty(), config);
+    parser.unnamed_count = position;
+    parser.passthrough = false;
+    match parser.parse_arg(arg.to_string(), 1) {
+        Ok(()) => parser.pending.len() == 1 && matches!(parser.pending[0].kind, EventKind::Unnamed(_)),
+        Err(_) => false,
     }
+}
+
+
+/// Parses the argument list according to the provided configuration.
+/// The result is either an error or the parsed arguments.
+///
+/// Note that the argument list passed to this function cannot contain non-unicode characters.
+pub fn parse<I: Iterator<Item=String>>(args: I, config: &ParsingConfig) -> Result<ParsedArgs, ParsingError> {
+    let mut parser = Parser::new(args, config);
+    let mut parsed_args = ParsedArgs::new(std::mem::take(&mut parser.name));
+    for event in parser {
+        parsed_args.apply(event?, config);
+    }
+    Ok(parsed_args)
+}
 
-    Ok(ParsedArgs {
-        name,
-        flags,
-        params,
-        unnameds,
-        subcommand,
-    })
+/// Parses the argument list according to the provided configuration, for a command whose subcommand is parsed afterwards through `ParsedArgs::parse_subcommand`.
+/// The result is either an error or the parsed arguments.
+///
+/// The argument list is interpreted exactly like by `parse`.
+/// If a subcommand is found, required global parameters that are missing are not reported, since they can still be specified after the subcommand.
+/// They are checked by `ParsedArgs::parse_subcommand` instead, so the results must be passed on to it.
+///
+/// Note that the argument list passed to this function cannot contain non-unicode characters.
+pub fn parse_with_subcommands<I: Iterator<Item=String>>(args: I, config: &ParsingConfig) -> Result<ParsedArgs, ParsingError> {
+    let mut parser = Parser::new(args, config);
+    parser.defer_globals = true;
+    let mut parsed_args = ParsedArgs::new(std::mem::take(&mut parser.name));
+    for event in &mut parser {
+        parsed_args.apply(event?, config);
+    }
+    parsed_args.deferred_globals = parser.deferred_globals;
+    Ok(parsed_args)
+}
+
+/// Parses the argument list according to the provided configuration and returns the resulting events in order.
+/// The result is either an error or the events.
+///
+/// Unlike the results of `parse`, the events preserve the order in which options and unnamed parameters were specified.
+/// The first argument, the name of the command, does not produce an event.
+///
+/// Note that the argument list passed to this function cannot contain non-unicode characters.
+pub fn parse_events<I: Iterator<Item=String>>(args: I, config: &ParsingConfig) -> Result<Vec<Event>, ParsingError> {
+    Parser::new(args, config).collect()
+}
+
+/// Parses the argument list according to the provided configuration, without storing the unnamed parameters.
+/// The result is either an error or an iterator over the unnamed parameters.
+///
+/// The whole argument list is validated up front by a first pass over a clone of `args`, which interprets the options and discards the unnamed parameters.
+/// An error anywhere in the argument list, including missing required parameters or a missing required subcommand, is therefore returned by this function.
+/// The returned iterator then parses the argument list a second time and yields the unnamed parameters as it advances,
+/// so that memory usage does not grow with the number of unnamed parameters.
+/// Since `args` is iterated twice, it should produce the same arguments both times.
+///
+/// All options up to the first unnamed parameter, double hyphen marker or subcommand have been interpreted when the iterator is returned.
+/// The arguments that follow a subcommand are not stored either, they are returned by `LazyArgs::into_subcommand_args`.
+///
+/// Note that the argument list passed to this function cannot contain non-unicode characters.
+pub fn parse_lazy<I: Iterator<Item=String> + Clone>(args: I, config: &ParsingConfig) -> Result<LazyArgs<'_, I>, ParsingError> {
+    let mut parser = Parser::new(args.clone(), config);
+    parser.leave_rest = true;
+    for event in parser {
+        event?;
+    }
+    LazyArgs::new(Parser::new(args, config), config)
+}
+
+/// Parses only the options that are known to the provided configuration, and returns the other arguments untouched.
+/// The result is either an error or the parsed arguments and the remaining arguments.
+///
+/// The remaining arguments are the unrecognized options, the unnamed parameters and the double hyphen marker, in their original order.
+/// They are not stored in the parsed arguments, so that they can be parsed again with another configuration.
+/// Unrecognized options are handled as if unknown option passthrough is enabled, so only declared passthrough parameters keep their values with them.
+/// Subcommands are interpreted as usual.
+///
+/// Note that the argument list passed to this function cannot contain non-unicode characters.
+pub fn parse_known<I: Iterator<Item=String>>(args: I, config: &ParsingConfig) -> Result<(ParsedArgs, Vec<String>), ParsingError> {
+    let mut parser = Parser::new(args, config);
+    parser.passthrough = true;
+    let mut parsed_args = ParsedArgs::new(std::mem::take(&mut parser.name));
+    let mut remainder = Vec::new();
+    for event in parser {
+        let event = event?;
+        match event.kind {
+            EventKind::Unknown(arg) | EventKind::Unnamed(arg) => remainder.push(arg),
+            EventKind::Marker => remainder.push(String::from("--")),
+            _ => parsed_args.apply(event, config),
+        }
+    }
+    Ok((parsed_args, remainder))
 }
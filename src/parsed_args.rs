@@ -2,17 +2,178 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::str::FromStr;
 
+use event::Event;
+use event::EventKind;
+use occurrence::Occurrence;
+use external::ExternalSubcommand;
+use parsing_error::ParsingError;
+use parsing_config::ParsingConfig;
+use parsing_warning::ParsingWarning;
+use parse::names_equal;
+use parse::Parser;
+
 
 /// Contains an argument list in parsed format.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParsedArgs {
     pub(crate) name: String,
     pub(crate) flags: HashSet<String>,
+    pub(crate) off_flags: HashSet<String>,
     pub(crate) params: HashMap<String, String>,
+    pub(crate) implicits: HashSet<String>,
     pub(crate) unnameds: Vec<String>,
     pub(crate) subcommand: Vec<String>,
+    pub(crate) subcommand_alias: Option<String>,
+    pub(crate) external: Option<ExternalSubcommand>,
+    pub(crate) flag_occurrences: HashMap<String, Vec<Occurrence>>,
+    pub(crate) param_occurrences: HashMap<String, Vec<Occurrence>>,
+    pub(crate) unnamed_occurrences: Vec<Occurrence>,
+    pub(crate) unknowns: Vec<String>,
+    pub(crate) warnings: Vec<ParsingWarning>,
+    pub(crate) deferred_globals: Vec<String>,
 }
 
 impl ParsedArgs {
+    pub(crate) fn new(name: String) -> Self {
+        Self {
+            name,
+            flags: HashSet::new(),
+            off_flags: HashSet::new(),
+            params: HashMap::new(),
+            implicits: HashSet::new(),
+            unnameds: Vec::new(),
+            subcommand: Vec::new(),
+            subcommand_alias: None,
+            external: None,
+            flag_occurrences: HashMap::new(),
+            param_occurrences: HashMap::new(),
+            unnamed_occurrences: Vec::new(),
+            unknowns: Vec::new(),
+            deferred_globals: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Records the warnings for an option that was specified through the `name`, which can be an alias, as `typed`.
+    fn warn(&mut self, config: &ParsingConfig, target: &str, name: &str, typed: &str) {
+        if !names_equal(config, name, typed) {
+            self.warnings.push(ParsingWarning::Abbreviated(typed.to_string(), name.to_string()));
+        }
+        if let Some(replacement) = config.deprecated.get(name).or_else(|| config.deprecated.get(target)) {
+            self.warnings.push(ParsingWarning::Deprecated(name.to_string(), replacement.clone()));
+        }
+    }
+
+    pub(crate) fn apply(&mut self, event: Event, config: &ParsingConfig) {
+        match (&event.kind, &event.typed) {
+            (EventKind::Flag(target), Some(typed)) | (EventKind::FlagOff(target), Some(typed)) | (EventKind::Param(target, _), Some(typed)) => {
+                let name = event.alias.as_deref().unwrap_or(target);
+                self.warn(config, target, name, typed);
+            },
+            _ => {},
+        }
+        let occurrence = Occurrence {
+            index: event.index,
+            typed: event.typed.unwrap_or_default(),
+            syntax: event.syntax,
+        };
+        match event.kind {
+            EventKind::Flag(name) => {
+                self.off_flags.remove(&name);
+                self.flag_occurrences.entry(name.clone()).or_default().push(occurrence);
+                self.flags.insert(name);
+            },
+            EventKind::FlagOff(name) => {
+                self.flags.remove(&name);
+                self.flag_occurrences.entry(name.clone()).or_default().push(occurrence);
+                self.off_flags.insert(name);
+            },
+            EventKind::Param(name, value) => {
+                if event.implicit {
+                    self.implicits.insert(name.clone());
+                } else {
+                    self.implicits.remove(&name);
+                }
+                self.param_occurrences.entry(name.clone()).or_default().push(occurrence);
+                self.params.insert(name, value);
+            },
+            EventKind::Unnamed(value) => {
+                self.unnamed_occurrences.push(occurrence);
+                self.unnameds.push(value);
+            },
+            EventKind::Unknown(value) => self.unknowns.push(value),
+            EventKind::Marker => if config.store_dh_marker {
+                self.unnamed_occurrences.push(occurrence);
+                self.unnameds.push(String::from("--"));
+            },
+            EventKind::Subcommand(name, args) => {
+                self.subcommand_alias = event.alias;
+                self.subcommand.push(name);
+                self.subcommand.extend(args);
+            },
+            EventKind::External(external) => {
+                self.subcommand.push(external.name.clone());
+                self.subcommand.extend(external.args.iter().cloned());
+                self.external = Some(external);
+            },
+        }
+    }
+
+    /// Takes over the global options that the `parent` has and that were not specified in these results, where `config` is the configuration that produced these results.
+    /// The `parent` holds the results of the command to which the subcommand belongs, which may have been merged with its own parent already.
+    ///
+    /// Afterwards these results hold every global option that was specified before or after the subcommand.
+    /// An option that was specified at both levels keeps the value that was specified after the subcommand.
+    /// Occurrences are not taken over, since they refer to positions in the parent's argument list.
+    pub fn merge_globals(&mut self, parent: &ParsedArgs, config: &ParsingConfig) {
+        for name in config.globals.iter() {
+            if self.flags.contains(name) || self.off_flags.contains(name) || self.params.contains_key(name) {
+                continue;
+            }
+            if parent.flags.contains(name) {
+                self.flags.insert(name.clone());
+            } else if parent.off_flags.contains(name) {
+                self.off_flags.insert(name.clone());
+            } else if let Some(value) = parent.params.get(name) {
+                self.params.insert(name.clone(), value.clone());
+                if parent.implicits.contains(name) {
+                    self.implicits.insert(name.clone());
+                }
+            }
+        }
+    }
+
+    /// Parses the arguments of the subcommand in these results according to the `config` of the subcommand, and takes over the global options of these results through `merge_globals`.
+    /// The result is either an error or the combined results of the subcommand.
+    ///
+    /// These results must have been produced by `parse_with_subcommands` or by `parse_subcommand` itself.
+    /// Required global parameters that were missing before the subcommand, at this level or any level above it, are reported missing if the arguments of the subcommand do not specify them either.
+    /// If the subcommand has a subcommand of its own, that check is left to the next call to `parse_subcommand`.
+    /// If no subcommand was found, the arguments are empty and only the global options are taken over.
+    ///
+    /// Note that the arguments of the subcommand cannot contain non-unicode characters.
+    pub fn parse_subcommand(&self, config: &ParsingConfig) -> Result<ParsedArgs, ParsingError> {
+        let mut parser = Parser::new(self.subcommand.iter().cloned(), config);
+        parser.defer_globals = true;
+        let mut result = ParsedArgs::new(std::mem::take(&mut parser.name));
+        for event in &mut parser {
+            result.apply(event?, config);
+        }
+        result.merge_globals(self, config);
+
+        let mut missing: Vec<String> = self.deferred_globals.iter().chain(parser.deferred_globals.iter())
+            .filter(|x| !result.params.contains_key(x.as_str()))
+            .cloned()
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        if result.subcommand.is_empty() && !missing.is_empty() {
+            return Err(ParsingError::MissingRequiredParameters(missing));
+        }
+        result.deferred_globals = missing;
+        Ok(result)
+    }
+
     /// Returns a reference to the first argument.
     pub fn name(&self) -> &str {
         self.name.as_str()
@@ -23,20 +184,28 @@ impl ParsedArgs {
         self.flags.contains(name)
     }
 
+    /// Returns `true` if the specified flag was explicitly turned off in the argument list.
+    ///
+    /// A flag is turned off through the plus syntax.
+    /// Only the last occurrence of a flag counts, so a flag is never both set and turned off.
+    pub fn has_flag_off(&self, name: &str) -> bool {
+        self.off_flags.contains(name)
+    }
+
     /// Returns the value that is associated with the specified parameter.
     pub fn get_param(&self, name: &str) -> Option<&str> {
-        match self.params.get(name) {
-            Some(value) => Some(value.as_str()),
-            None => None,
-        }
+        self.params.get(name).map(|value| value.as_str())
     }
 
     /// Returns the value that is associated with the specified parameter parsed to the specified type.
     pub fn get_param_as<T: FromStr>(&self, name: &str) -> Option<Result<T, T::Err>> {
-        match self.params.get(name) {
-            Some(value) => Some(value.parse()),
-            None => None,
-        }
+        self.params.get(name).map(|value| value.parse())
+    }
+
+    /// Returns `true` if the specified parameter has its implicit value, because it was specified without a value.
+    /// This can only be the case for a parameter with an optional value.
+    pub fn used_implicit(&self, name: &str) -> bool {
+        self.implicits.contains(name)
     }
 
     /// Returns a reference to the vector of unnamed parameters.
@@ -44,12 +213,56 @@ impl ParsedArgs {
         &self.unnameds
     }
 
+    /// Returns the occurrences of the specified flag in the argument list, in order.
+    ///
+    /// Both the occurrences that set the flag and those that turned it off are included.
+    pub fn flag_occurrences(&self, name: &str) -> &[Occurrence] {
+        self.flag_occurrences.get(name).map_or(&[], |value| value.as_slice())
+    }
+
+    /// Returns the occurrences of the specified parameter in the argument list, in order.
+    ///
+    /// A parameter can only occur more than once if parameter duplication is enabled, in which case the last occurrence set its value.
+    pub fn param_occurrences(&self, name: &str) -> &[Occurrence] {
+        self.param_occurrences.get(name).map_or(&[], |value| value.as_slice())
+    }
+
+    /// Returns the occurrences of the unnamed parameters.
+    /// The occurrence at each position belongs to the unnamed parameter at the same position in `unnamed_params`.
+    pub fn unnamed_occurrences(&self) -> &[Occurrence] {
+        &self.unnamed_occurrences
+    }
+
+    /// Returns the unrecognized options and their values, in the order in which they occurred.
+    ///
+    /// This list can only contain arguments if unknown option passthrough is enabled.
+    pub fn unknown_args(&self) -> &[String] {
+        &self.unknowns
+    }
+
+    /// Returns the warnings for the argument list, in the order in which they occurred.
+    ///
+    /// Warnings are produced for deprecated options and for options that were specified by an abbreviation.
+    pub fn warnings(&self) -> &[ParsingWarning] {
+        &self.warnings
+    }
+
     /// Returns a reference to the name of the subcommand that is being invoked.
     pub fn get_subcommand(&self) -> Option<&str> {
-        match self.subcommand.get(0) {
-            Some(value) => Some(value.as_str()),
-            None => None,
-        }
+        self.subcommand.first().map(|value| value.as_str())
+    }
+
+    /// Returns the name of the alias through which the subcommand was specified, if an alias was used.
+    pub fn subcommand_alias(&self) -> Option<&str> {
+        self.subcommand_alias.as_deref()
+    }
+
+    /// Returns the subcommand that is being invoked if it is implemented by a separate executable.
+    ///
+    /// This is only the case when parsing with `parse_with_externals`.
+    /// The name and arguments of such a subcommand are also returned by `get_subcommand` and `subcommand_args`.
+    pub fn external_subcommand(&self) -> Option<&ExternalSubcommand> {
+        self.external.as_ref()
     }
 
     /// Returns a reference to the vector that holds the arguments for the subcommand which is being invoked.
@@ -1,15 +1,41 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use config_error::ConfigError;
+#[cfg(feature = "serde")]
+use config_desc::ConfigDesc;
+
 
 #[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub(crate) enum ArgDesc {
     Flag,
     Param(bool),
+    OptionalParam(String),
     Alias(String),
 }
 
 
+/// Computes the completion candidates for the values of a parameter from the partially typed value.
+pub(crate) type ValueCompleter = Box<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+
+/// Controls how arguments that start with a single hyphen are interpreted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SingleHyphenMode {
+    /// Every character after the hyphen is the name of an option with a name of one character.
+    Clustered,
+
+    /// Everything after the hyphen is the name of a single option.
+    Long,
+
+    /// Everything after the hyphen is first tried as the name of a single option.
+    /// If no option matches, every character after the hyphen is tried as the name of an option instead.
+    LongOrClustered,
+}
+
+
 /// Controls how the argument list is interpreted.
 ///
 /// A `ParsingConfig` struct must be configured before parsing an argument list.
@@ -26,9 +52,14 @@ pub(crate) enum ArgDesc {
 /// - double hyphen syntax
 /// - single hyphen syntax
 /// - assignment syntax
+/// - slash syntax
+/// - plus syntax
 /// - subcommands
+/// - global options
 /// - option completion
 /// - subcommand completion
+/// - choices
+/// - case-insensitive matching
 ///
 /// ### Parameters, flags and unnamed parameters
 ///
@@ -37,6 +68,11 @@ pub(crate) enum ArgDesc {
 /// Flags are options with a name which do not take a value.
 /// Unnamed parameters are options without a name, they are the arguments that are not interpreted by any of the syntaxes or features.
 ///
+/// Parameters can also be added with an optional value.
+/// Such a parameter takes its value only from the same argument in which it is specified, never from the next argument.
+/// When it is specified without a value, it gets the implicit value that it was added with instead.
+/// A parameter with an optional value is never required.
+///
 /// ### Double hyphen assignment syntax
 ///
 /// Double hyphen assignment syntax can only specify parameter options.
@@ -65,6 +101,8 @@ pub(crate) enum ArgDesc {
 /// Where "NAME" and "VALUE" can both be replaced by any appropriate value.
 /// In the latter case, the next argument ("VALUE") will always be used to set the parameter's value, regardless of what it might be.
 ///
+/// Parameters with an optional value are specified like flags, which sets them to their implicit value.
+///
 /// If option completion is enabled, "NAME" can also be any string that is a prefix of the intended option's name.
 /// Note that in that case, it is an error if "NAME" matches prefixes of multiple names.
 ///
@@ -90,6 +128,38 @@ pub(crate) enum ArgDesc {
 /// Note that as much flags as desired can be specified in one argument, but at most one parameter.
 /// And if there is one, the parameter must always be the last option to be specified.
 ///
+/// Parameters with an optional value can be specified anywhere in "FLAGS", in which case they get their implicit value.
+/// If such a parameter is the last option to be specified, it gets the value "495" in the latter case instead.
+///
+/// ##### Long options
+///
+/// The single hyphen syntax can also be configured to introduce options with longer names, like the double hyphen syntax does.
+/// Which interpretation is used is controlled by the `SingleHyphenMode` of the `ParsingConfig` object.
+///
+/// In the `Long` mode it is used as follows for flags:
+///
+/// > -NAME
+///
+/// And as follows for parameters:
+///
+/// > -NAME VALUE
+///
+/// Where "NAME" and "VALUE" can both be replaced by any appropriate value.
+/// The next argument ("VALUE") will always be used to set the parameter's value, regardless of what it might be.
+/// Parameters with an optional value are specified like flags, which sets them to their implicit value.
+/// If option completion is enabled, "NAME" can also be any string that is a prefix of the intended option's name.
+/// Note that in that case, it is an error if "NAME" matches prefixes of multiple names.
+///
+/// A parameter can also be given a value in the same argument, as in "-NAME495", where the value starts at the first character that cannot be part of a name.
+/// This is the only way to assign a value to a parameter with an optional value in the `Long` mode.
+///
+/// In the `LongOrClustered` mode "NAME" is first interpreted as in the `Long` mode.
+/// If it doesn't match any option, the argument is interpreted as a cluster of options with names of one character instead, as described above.
+/// An exact name always takes precedence over a cluster, and so does a unique prefix if option completion is enabled.
+/// If "NAME" is a prefix of multiple names, it is an error rather than a cluster.
+///
+/// In both modes, arguments in which the hyphen is not followed by an alphabetic character are interpreted as in the `Clustered` mode.
+///
 /// ##### Naked hyphen
 ///
 /// The naked hyphen is a special case in the single hyphen syntax.
@@ -137,6 +207,46 @@ pub(crate) enum ArgDesc {
 /// If option completion is enabled, "NAME" can also be any string that is a prefix of the intended option's name.
 /// Note that in that case, it is an error if "NAME" matches prefixes of multiple names.
 ///
+/// ### Slash syntax
+///
+/// Slash syntax can specify both flags and parameters.
+///
+/// It is used as follows for flags:
+///
+/// > /NAME
+///
+/// It is used as follows for parameters:
+///
+/// > /NAME:VALUE
+///
+/// Or as follows:
+///
+/// > /NAME VALUE
+///
+/// Where "NAME" and "VALUE" can both be replaced by any appropriate value.
+/// The separator between "NAME" and "VALUE" is a colon by default, but it can be changed to an equals sign.
+/// In the last case, the next argument ("VALUE") will always be used to set the parameter's value, regardless of what it might be.
+/// Parameters with an optional value never take the next argument, the first form sets them to their implicit value.
+///
+/// Unlike in the other syntaxes, "NAME" is never completed, it must be the full name of an option (matched case-insensitively if case-insensitive matching is enabled).
+/// If "NAME" doesn't match any option, or if it contains another slash, the argument is interpreted as an unnamed parameter instead.
+/// This way absolute paths such as "/tmp" can still be passed as unnamed parameters, even if an option name starts with "tmp".
+///
+/// ### Plus syntax
+///
+/// Plus syntax can only specify flag options, and turns them off.
+/// Like the single hyphen syntax, it only works for options that have a name of one character.
+///
+/// It is used as follows:
+///
+/// > +FLAGS
+///
+/// Where each character in the string "FLAGS" must be the name of a flag option.
+/// Each of these flags will be recorded as explicitly turned off, instead of being set.
+/// When the same flag is both set and turned off in the argument list, the last occurrence wins.
+///
+/// Arguments that consist of only a plus sign or that contain non-alphabetic characters, such as "+5", are interpreted as unnamed parameters.
+///
 /// ### Subcommands
 ///
 /// Subcommands can be enabled by adding them to the `ParsingConfig` object.
@@ -148,21 +258,86 @@ pub(crate) enum ArgDesc {
 /// If the subcommand completion feature is enabled as well, any string that is a prefix of a subcommand will work as a subcommand.
 /// Note that in that case, it is an error if the argument matches prefixes of multiple subcommands.
 ///
+/// Subcommands can have aliases, which are interpreted exactly like the subcommand they point to.
+/// The results always contain the name of the subcommand, the alias that was used is available separately.
+///
 /// It is also possible to set whether a subcommand is required or not.
 /// If it is required, and the subcommand index feature is enabled as well, it is an error if no subcommand is found at the specified index in the unnamed parameter list.
+///
+/// ##### Global options
+///
+/// Flags and parameters can be declared global, so they can be specified both before and after a subcommand.
+/// The `ParsingConfig` object of a subcommand takes over the global options of its parent through `inherit_globals`.
+/// The argument list is parsed through `parse_with_subcommands`, after which the arguments of the subcommand are parsed through `ParsedArgs::parse_subcommand`, which combines the results with those of the parent.
+/// This works for any depth of nested subcommands, as long as every level inherits from the one above it.
+///
+/// Through these functions a required global parameter is only reported missing once the whole chain of subcommands has been parsed, since it can be specified at any level.
+/// Every other way of parsing reports it missing if it was not specified before the subcommand.
+///
+/// ### Unknown option passthrough
+///
+/// If unknown option passthrough is enabled, an argument that would otherwise cause an unrecognized option error is stored in a separate list instead.
+/// This way options that are meant for another program can be forwarded to it.
+/// The whole argument is stored, even if it is a cluster of single hyphen options of which only some are unrecognized.
+///
+/// An unrecognized option that takes a value cannot be told apart from a flag followed by an unnamed parameter.
+/// Therefore, only the options that are declared as passthrough parameters take the next argument along with them.
+/// Values that are part of the same argument, as in "--NAME=VALUE", are always kept together.
+///
+/// ### Option completion
+///
+/// If option completion is enabled, an option can be specified by any prefix of its name, as long as that prefix does not match other options.
+/// A prefix that matches both an option and aliases to that option is not ambiguous, the option itself is preferred over its aliases.
+///
+/// A minimum prefix length can be set, shorter prefixes are never completed.
+/// Completion can also be disabled for individual options, which can then only be specified by their full name.
+/// Options that are hidden are never completed either.
+///
+/// ### Deprecated options
+///
+/// Flags, parameters and aliases can be marked as deprecated, optionally with the name of a replacement.
+/// They still work as before, but specifying them produces a warning that is stored alongside the results.
+/// A warning is also produced when an option is specified by an abbreviation of its name.
+///
+/// ### Prompting
+///
+/// Instead of failing when required parameters are missing, `parse_with_prompter` asks for their values through a `Prompter`.
+/// Parameters can be marked as secret, so that their values are not shown while they are typed.
+///
+/// ### Choices
+///
+/// The values of a parameter can be restricted to a fixed list of choices.
+/// It is an error if such a parameter is assigned a value that is not in the list.
+///
+/// ### Case-insensitive matching
+///
+/// If case-insensitive matching is enabled, option names, subcommand names and choices are matched regardless of their case.
+/// An exact match is always preferred over a match that differs only in case.
+/// The results and errors always contain the names and choices as they were added to the `ParsingConfig` object.
+///
+/// Options with a name of one character that are specified in a cluster of single hyphen options, or through plus syntax, are still matched case-sensitively.
+/// This way "-v" and "-V" can remain two different options.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "ConfigDesc"))]
 pub struct ParsingConfig {
     pub(crate) dh_marker: bool,
     pub(crate) store_dh_marker: bool,
     pub(crate) sh_syntax: bool,
+    pub(crate) sh_mode: SingleHyphenMode,
     pub(crate) dh_syntax: bool,
     pub(crate) dha_syntax: bool,
     pub(crate) a_syntax: bool,
+    pub(crate) slash_syntax: bool,
+    pub(crate) slash_separator: char,
+    pub(crate) plus_syntax: bool,
 
     pub(crate) param_stacking: bool,
     pub(crate) param_duplication: bool,
+    pub(crate) unknown_passthrough: bool,
 
     pub(crate) option_completion: bool,
     pub(crate) subcmd_completion: bool,
+    pub(crate) completion_min_length: usize,
+    pub(crate) case_insensitive: bool,
 
     pub(crate) subcmd_index: bool,
     pub(crate) subcmd_required: bool,
@@ -170,6 +345,23 @@ pub struct ParsingConfig {
 
     pub(crate) options: HashMap<String, ArgDesc>,
     pub(crate) subcmds: HashSet<String>,
+    pub(crate) subcmd_aliases: HashMap<String, String>,
+    pub(crate) choices: HashMap<String, Vec<String>>,
+    pub(crate) no_completion: HashSet<String>,
+    pub(crate) deprecated: HashMap<String, Option<String>>,
+    pub(crate) hidden: HashSet<String>,
+    pub(crate) secrets: HashSet<String>,
+    pub(crate) globals: HashSet<String>,
+    pub(crate) passthrough_params: HashSet<String>,
+    pub(crate) descriptions: HashMap<String, String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) value_completers: HashMap<String, ValueCompleter>,
+}
+
+impl Default for ParsingConfig {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ParsingConfig {
@@ -179,15 +371,22 @@ impl ParsingConfig {
             dh_marker: true,
             store_dh_marker: false,
             sh_syntax: true,
+            sh_mode: SingleHyphenMode::Clustered,
             dh_syntax: true,
             dha_syntax: true,
             a_syntax: false,
+            slash_syntax: false,
+            slash_separator: ':',
+            plus_syntax: false,
 
             param_stacking: true,
             param_duplication: false,
+            unknown_passthrough: false,
 
             option_completion: true,
             subcmd_completion: false,
+            completion_min_length: 0,
+            case_insensitive: false,
 
             subcmd_index: false,
             subcmd_required: false,
@@ -195,6 +394,16 @@ impl ParsingConfig {
 
             options: HashMap::new(),
             subcmds: HashSet::new(),
+            subcmd_aliases: HashMap::new(),
+            choices: HashMap::new(),
+            no_completion: HashSet::new(),
+            deprecated: HashMap::new(),
+            hidden: HashSet::new(),
+            secrets: HashSet::new(),
+            globals: HashSet::new(),
+            passthrough_params: HashSet::new(),
+            descriptions: HashMap::new(),
+            value_completers: HashMap::new(),
         }
     }
 
@@ -204,15 +413,22 @@ impl ParsingConfig {
             dh_marker: false,
             store_dh_marker: false,
             sh_syntax: false,
+            sh_mode: SingleHyphenMode::Clustered,
             dh_syntax: false,
             dha_syntax: false,
             a_syntax: false,
+            slash_syntax: false,
+            slash_separator: ':',
+            plus_syntax: false,
 
             param_stacking: false,
             param_duplication: false,
+            unknown_passthrough: false,
 
             option_completion: false,
             subcmd_completion: false,
+            completion_min_length: 0,
+            case_insensitive: false,
 
             subcmd_index: false,
             subcmd_required: false,
@@ -220,6 +436,16 @@ impl ParsingConfig {
 
             options: HashMap::new(),
             subcmds: HashSet::new(),
+            subcmd_aliases: HashMap::new(),
+            choices: HashMap::new(),
+            no_completion: HashSet::new(),
+            deprecated: HashMap::new(),
+            hidden: HashSet::new(),
+            secrets: HashSet::new(),
+            globals: HashSet::new(),
+            passthrough_params: HashSet::new(),
+            descriptions: HashMap::new(),
+            value_completers: HashMap::new(),
         }
     }
 
@@ -252,6 +478,13 @@ impl ParsingConfig {
         self.sh_syntax = value;
     }
 
+    /// Sets how arguments in the single hyphen syntax are interpreted.
+    ///
+    /// The default value is `SingleHyphenMode::Clustered`.
+    pub fn set_single_hyphen_mode(&mut self, value: SingleHyphenMode) {
+        self.sh_mode = value;
+    }
+
     /// Enables or disables double hyphen syntax.
     ///
     /// Enabled by default.
@@ -273,6 +506,32 @@ impl ParsingConfig {
         self.a_syntax = value;
     }
 
+    /// Enables or disables slash syntax.
+    ///
+    /// Disabled by default.
+    pub fn set_slash_syntax(&mut self, value: bool) {
+        self.slash_syntax = value;
+    }
+
+    /// Sets the character that separates the name from the value in slash syntax.
+    ///
+    /// The default value is a colon.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `value` is neither a colon nor an equals sign.
+    pub fn set_slash_separator(&mut self, value: char) {
+        assert!(value == ':' || value == '=', "clargs: slash separator must be a colon or an equals sign");
+        self.slash_separator = value;
+    }
+
+    /// Enables or disables plus syntax.
+    ///
+    /// Disabled by default.
+    pub fn set_plus_syntax(&mut self, value: bool) {
+        self.plus_syntax = value;
+    }
+
 
     /// Enables or disables single hyphen parameter stacking.
     ///
@@ -290,6 +549,21 @@ impl ParsingConfig {
     }
 
 
+    /// Enables or disables unknown option passthrough.
+    /// Unrecognized options are stored in order instead of being seen as an error if enabled.
+    ///
+    /// Disabled by default.
+    pub fn set_unknown_passthrough(&mut self, value: bool) {
+        self.unknown_passthrough = value;
+    }
+
+    /// Declares that an unrecognized option with the `name` takes a value, so that the next argument is passed through along with it.
+    /// The `name` is given without the syntax around it, as in "foo" for "--foo".
+    pub fn add_passthrough_param(&mut self, name: String) {
+        self.passthrough_params.insert(name);
+    }
+
+
     /// Enables or disables argument completion.
     ///
     /// Enabled by default.
@@ -297,6 +571,32 @@ impl ParsingConfig {
         self.option_completion = value;
     }
 
+    /// Sets the minimum length of a prefix that completes to an option.
+    /// Shorter prefixes only match options whose name they are equal to.
+    ///
+    /// The default value is zero.
+    pub fn set_option_completion_min_length(&mut self, value: usize) {
+        self.completion_min_length = value;
+    }
+
+    /// Enables or disables completion for a single flag, parameter or alias.
+    /// If disabled, the option can only be specified by its full name, and its prefixes match only other options.
+    /// This can be used for options that should never be specified by accident, such as "delete-all".
+    ///
+    /// Enabled by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a flag, parameter or alias.
+    pub fn set_option_completion_for(&mut self, name: String, value: bool) {
+        assert!(self.options.contains_key(&name), "clargs: completion can only be set for a flag, parameter or alias");
+        if value {
+            self.no_completion.remove(&name);
+        } else {
+            self.no_completion.insert(name);
+        }
+    }
+
     /// Enables or disables subcommand completion.
     /// Subcommand completion can occur only if the subcommand index feature is enabled.
     ///
@@ -305,6 +605,15 @@ impl ParsingConfig {
         self.subcmd_completion = value;
     }
 
+    /// Enables or disables case-insensitive matching of option names, subcommand names and choices.
+    /// Options that are specified through a cluster of single hyphen options, or through plus syntax, are still matched case-sensitively.
+    /// While it is enabled, names of more than one character that differ only in case from a name that is already used cannot be added.
+    ///
+    /// Disabled by default.
+    pub fn set_case_insensitive(&mut self, value: bool) {
+        self.case_insensitive = value;
+    }
+
 
     /// Enables or disables the index at which the subcommand is expected to be found in the unnamed parameters list.
     /// It is a parsing error if this is enabled, a subcommand is required and there is no subcommand found at the specified position.
@@ -329,16 +638,41 @@ impl ParsingConfig {
     }
 
 
+    fn check_name(&self, name: &str) -> Result<(), ConfigError> {
+        if !Self::is_valid_name(name) {
+            return Err(ConfigError::InvalidName(name.to_string()));
+        }
+        if self.options.contains_key(name) || self.subcmds.contains(name) || self.subcmd_aliases.contains_key(name) {
+            return Err(ConfigError::NameTaken(name.to_string()));
+        }
+        if self.case_insensitive && name.chars().count() > 1 {
+            let lowercase = name.to_lowercase();
+            let names = self.options.keys().chain(self.subcmds.iter()).chain(self.subcmd_aliases.keys());
+            if let Some(other) = names.filter(|x| x.to_lowercase() == lowercase).min() {
+                return Err(ConfigError::NameTakenIgnoringCase(name.to_string(), other.to_string()));
+            }
+        }
+        Ok(())
+    }
+
     /// Adds a flag to the configuration.
     ///
     /// # Panics
     ///
     /// Panics if the `name` is already taken or if it is an invalid name.
     pub fn add_flag(&mut self, name: String) {
-        assert!(Self::is_valid_name(&name), "clargs: invalid flag name");
-        assert!(!self.options.contains_key(&name), "clargs: flag name is already taken");
-        assert!(!self.subcmds.contains(&name), "clargs: flag name is already taken");
+        if let Err(error) = self.try_add_flag(name) {
+            panic!("clargs: {}", error);
+        }
+    }
+
+    /// Adds a flag to the configuration.
+    ///
+    /// Returns an error if the `name` is already taken or if it is an invalid name.
+    pub fn try_add_flag(&mut self, name: String) -> Result<(), ConfigError> {
+        self.check_name(&name)?;
         self.options.insert(name, ArgDesc::Flag);
+        Ok(())
     }
 
     /// Adds a parameter to the configuration.
@@ -347,10 +681,40 @@ impl ParsingConfig {
     ///
     /// Panics if the `name` is already taken or if it is an invalid name.
     pub fn add_param(&mut self, name: String, required: bool) {
-        assert!(Self::is_valid_name(&name), "clargs: invalid parameter name");
-        assert!(!self.options.contains_key(&name), "clargs: parameter name is already taken");
-        assert!(!self.subcmds.contains(&name), "clargs: parameter name is already taken");
+        if let Err(error) = self.try_add_param(name, required) {
+            panic!("clargs: {}", error);
+        }
+    }
+
+    /// Adds a parameter to the configuration.
+    ///
+    /// Returns an error if the `name` is already taken or if it is an invalid name.
+    pub fn try_add_param(&mut self, name: String, required: bool) -> Result<(), ConfigError> {
+        self.check_name(&name)?;
         self.options.insert(name, ArgDesc::Param(required));
+        Ok(())
+    }
+
+    /// Adds a parameter with an optional value to the configuration.
+    /// The parameter is set to the `implicit_value` when it is specified without a value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` is already taken or if it is an invalid name.
+    pub fn add_optional_param(&mut self, name: String, implicit_value: String) {
+        if let Err(error) = self.try_add_optional_param(name, implicit_value) {
+            panic!("clargs: {}", error);
+        }
+    }
+
+    /// Adds a parameter with an optional value to the configuration.
+    /// The parameter is set to the `implicit_value` when it is specified without a value.
+    ///
+    /// Returns an error if the `name` is already taken or if it is an invalid name.
+    pub fn try_add_optional_param(&mut self, name: String, implicit_value: String) -> Result<(), ConfigError> {
+        self.check_name(&name)?;
+        self.options.insert(name, ArgDesc::OptionalParam(implicit_value));
+        Ok(())
     }
 
     /// Adds an alias to a flag or parameter to the configuration.
@@ -361,16 +725,28 @@ impl ParsingConfig {
     /// Panics if the `name` is equal to the `target` or if the `target` does not point to a flag, parameter or other alias.
     /// Or if the `name` is already taken or if it is an invalid name.
     pub fn add_alias(&mut self, name: String, target: String) {
-        assert!(Self::is_valid_name(&name), "clargs: invalid alias name");
-        assert!(!self.options.contains_key(&name), "clargs: alias name is already taken");
-        assert!(!self.subcmds.contains(&name), "clargs: alias name is already taken");
-        assert!(name != target, "clargs: alias target cannot be the same as its name");
-        assert!(self.options.contains_key(&target), "clargs: alias must point to a valid target");
-        let target = match self.options.get(&target).unwrap() {
-            ArgDesc::Alias(target) => target.to_string(),
-            _ => target,
+        if let Err(error) = self.try_add_alias(name, target) {
+            panic!("clargs: {}", error);
+        }
+    }
+
+    /// Adds an alias to a flag or parameter to the configuration.
+    /// If the `target` is an alias as well, the new alias will point to that alias's `target`.
+    ///
+    /// Returns an error if the `name` is equal to the `target` or if the `target` does not point to a flag, parameter or other alias.
+    /// Or if the `name` is already taken or if it is an invalid name.
+    pub fn try_add_alias(&mut self, name: String, target: String) -> Result<(), ConfigError> {
+        self.check_name(&name)?;
+        if name == target {
+            return Err(ConfigError::AliasSelfReference(name));
+        }
+        let target = match self.options.get(&target) {
+            Some(ArgDesc::Alias(target)) => target.to_string(),
+            Some(_) => target,
+            None => return Err(ConfigError::AliasTargetMissing(name, target)),
         };
         self.options.insert(name, ArgDesc::Alias(target));
+        Ok(())
     }
 
     /// Adds a subcommand to the configuration.
@@ -379,10 +755,219 @@ impl ParsingConfig {
     ///
     /// Panics if the `name` is already taken, if it is an invalid name or if it is an empty string.
     pub fn add_subcommand(&mut self, name: String) {
-        assert!(!name.is_empty(), "clargs: subcommand name cannot be an empty string");
-        assert!(Self::is_valid_name(&name), "clargs: invalid subcommand name");
-        assert!(!self.options.contains_key(&name), "clargs: subcommand name is already taken");
-        assert!(!self.subcmds.contains(&name), "clargs: subcommand name is already taken");
+        if let Err(error) = self.try_add_subcommand(name) {
+            panic!("clargs: {}", error);
+        }
+    }
+
+    /// Adds a subcommand to the configuration.
+    ///
+    /// Returns an error if the `name` is already taken, if it is an invalid name or if it is an empty string.
+    pub fn try_add_subcommand(&mut self, name: String) -> Result<(), ConfigError> {
+        if name.is_empty() {
+            return Err(ConfigError::InvalidName(name));
+        }
+        self.check_name(&name)?;
         self.subcmds.insert(name);
+        Ok(())
+    }
+
+    /// Adds an alias to a subcommand to the configuration.
+    /// If the `target` is an alias as well, the new alias will point to that alias's `target`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` is equal to the `target` or if the `target` does not point to a subcommand or other subcommand alias.
+    /// Or if the `name` is already taken, if it is an invalid name or if it is an empty string.
+    pub fn add_subcommand_alias(&mut self, name: String, target: String) {
+        if let Err(error) = self.try_add_subcommand_alias(name, target) {
+            panic!("clargs: {}", error);
+        }
+    }
+
+    /// Adds an alias to a subcommand to the configuration.
+    /// If the `target` is an alias as well, the new alias will point to that alias's `target`.
+    ///
+    /// Returns an error if the `name` is equal to the `target` or if the `target` does not point to a subcommand or other subcommand alias.
+    /// Or if the `name` is already taken, if it is an invalid name or if it is an empty string.
+    pub fn try_add_subcommand_alias(&mut self, name: String, target: String) -> Result<(), ConfigError> {
+        if name.is_empty() {
+            return Err(ConfigError::InvalidName(name));
+        }
+        self.check_name(&name)?;
+        if name == target {
+            return Err(ConfigError::AliasSelfReference(name));
+        }
+        let target = match self.subcmd_aliases.get(&target) {
+            Some(target) => target.to_string(),
+            None if self.subcmds.contains(&target) => target,
+            None => return Err(ConfigError::AliasTargetMissing(name, target)),
+        };
+        self.subcmd_aliases.insert(name, target);
+        Ok(())
+    }
+
+    /// Restricts the values of a parameter to the `choices`.
+    /// It is a parsing error if the parameter is assigned any other value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a parameter.
+    pub fn set_choices(&mut self, name: String, choices: Vec<String>) {
+        match self.options.get(&name) {
+            Some(ArgDesc::Param(_)) | Some(ArgDesc::OptionalParam(_)) => {},
+            _ => panic!("clargs: choices can only be set for a parameter"),
+        }
+        self.choices.insert(name, choices);
+    }
+
+    /// Marks a flag, parameter or alias as deprecated, or removes that mark if `value` is `false`.
+    /// Specifying a deprecated option produces a warning, which mentions the `replacement` if there is one.
+    /// An alias to a deprecated option is deprecated as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a flag, parameter or alias.
+    pub fn set_deprecated(&mut self, name: String, value: bool, replacement: Option<String>) {
+        assert!(self.options.contains_key(&name), "clargs: only a flag, parameter or alias can be deprecated");
+        if value {
+            self.deprecated.insert(name, replacement);
+        } else {
+            self.deprecated.remove(&name);
+        }
+    }
+
+    /// Hides or shows a flag, parameter or alias.
+    /// A hidden option can still be specified by its full name, but it is never completed and it is left out of generated completions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a flag, parameter or alias.
+    pub fn set_hidden(&mut self, name: String, value: bool) {
+        assert!(self.options.contains_key(&name), "clargs: only a flag, parameter or alias can be hidden");
+        if value {
+            self.hidden.insert(name);
+        } else {
+            self.hidden.remove(&name);
+        }
+    }
+
+    /// Marks a parameter as secret, or removes that mark if `value` is `false`.
+    /// When a secret parameter is prompted for, the answer is not shown while it is typed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a parameter.
+    pub fn set_secret(&mut self, name: String, value: bool) {
+        match self.options.get(&name) {
+            Some(ArgDesc::Param(_)) | Some(ArgDesc::OptionalParam(_)) => {},
+            _ => panic!("clargs: only a parameter can be secret"),
+        }
+        if value {
+            self.secrets.insert(name);
+        } else {
+            self.secrets.remove(&name);
+        }
+    }
+
+    /// Declares a flag or parameter global, or removes that declaration if `value` is `false`.
+    /// Global options are taken over by subcommands through `inherit_globals`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a flag or parameter.
+    pub fn set_global(&mut self, name: String, value: bool) {
+        match self.options.get(&name) {
+            Some(ArgDesc::Alias(_)) | None => panic!("clargs: only a flag or parameter can be global"),
+            _ => {},
+        }
+        if value {
+            self.globals.insert(name);
+        } else {
+            self.globals.remove(&name);
+        }
+    }
+
+    /// Adds the global options of the `parent`, the configuration of the command to which this configuration's subcommand belongs.
+    /// Aliases to those options and their choices, descriptions, deprecation, visibility, secrecy and completion settings are added along with them.
+    /// The added options are global in this configuration as well, so they can be passed on to nested subcommands.
+    ///
+    /// Options and aliases whose name is already taken in this configuration are left out, so a subcommand can override a global option with its own.
+    /// Inherited parameters are never required, since they may already have been specified before the subcommand.
+    /// Instead `ParsedArgs::parse_subcommand` checks the parameters that are required in the `parent` once the results are combined.
+    /// Value completers are not inherited.
+    pub fn inherit_globals(&mut self, parent: &ParsingConfig) {
+        let mut added = HashSet::new();
+        for name in parent.globals.iter() {
+            if self.check_name(name).is_err() {
+                continue;
+            }
+            let desc = match parent.options.get(name) {
+                Some(ArgDesc::Flag) => ArgDesc::Flag,
+                Some(ArgDesc::Param(_)) => ArgDesc::Param(false),
+                Some(ArgDesc::OptionalParam(value)) => ArgDesc::OptionalParam(value.clone()),
+                _ => continue,
+            };
+            self.options.insert(name.clone(), desc);
+            self.globals.insert(name.clone());
+            if let Some(choices) = parent.choices.get(name) {
+                self.choices.insert(name.clone(), choices.clone());
+            }
+            if parent.secrets.contains(name) {
+                self.secrets.insert(name.clone());
+            }
+            added.insert(name.as_str());
+        }
+        for (name, desc) in parent.options.iter() {
+            match desc {
+                ArgDesc::Alias(target) if added.contains(target.as_str()) && self.check_name(name).is_ok() => {
+                    self.options.insert(name.clone(), ArgDesc::Alias(target.clone()));
+                    added.insert(name.as_str());
+                },
+                _ => {},
+            }
+        }
+        for name in added {
+            if let Some(description) = parent.descriptions.get(name) {
+                self.descriptions.insert(name.to_string(), description.clone());
+            }
+            if let Some(replacement) = parent.deprecated.get(name) {
+                self.deprecated.insert(name.to_string(), replacement.clone());
+            }
+            if parent.hidden.contains(name) {
+                self.hidden.insert(name.to_string());
+            }
+            if parent.no_completion.contains(name) {
+                self.no_completion.insert(name.to_string());
+            }
+        }
+    }
+
+    /// Sets the description of a flag, parameter, alias or subcommand, which is shown alongside completion candidates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a flag, parameter, alias or subcommand.
+    pub fn set_description(&mut self, name: String, description: String) {
+        assert!(self.options.contains_key(&name) || self.subcmds.contains(&name), "clargs: only a flag, parameter, alias or subcommand can have a description");
+        self.descriptions.insert(name, description);
+    }
+
+    /// Sets the function that computes the completion candidates for the values of a parameter while completing.
+    /// The function is called with the partially typed value, and only the returned values that start with it are suggested.
+    /// It is only used if the parameter has no choices.
+    ///
+    /// Note that value completers are not serialized.
+    /// The function must be `Send` and `Sync`, so that the `ParsingConfig` object can be shared between threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` does not point to a parameter.
+    pub fn set_value_completer<F: Fn(&str) -> Vec<String> + Send + Sync + 'static>(&mut self, name: String, completer: F) {
+        match self.options.get(&name) {
+            Some(ArgDesc::Param(_)) | Some(ArgDesc::OptionalParam(_)) => {},
+            _ => panic!("clargs: only a parameter can have a value completer"),
+        }
+        self.value_completers.insert(name, Box::new(completer));
     }
 }
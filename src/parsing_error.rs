@@ -7,6 +7,7 @@ use std::fmt::Error as FmtError;
 
 /// Returned when an error occurs during the parsing of an argument list.
 #[derive(PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParsingError {
     /// Indicates that an option was specified which the program does not recognize.
     ///
@@ -52,6 +53,24 @@ pub enum ParsingError {
     /// The second associated string is the name of the alias which was used to specify the option.
     MissingArgumentAlias(String, String),
 
+    /// Indicates that a parameter was turned off through the plus syntax, which is only allowed for flags.
+    ///
+    /// The associated string is the name of the aforementioned option.
+    ToggledParameter(String),
+
+    /// Indicates that a parameter was turned off through the plus syntax, which is only allowed for flags.
+    ///
+    /// The first associated string is the name of the aforementioned option.
+    /// The second associated string is the name of the alias which was used to specify the option.
+    ToggledParameterAlias(String, String),
+
+    /// Indicates that a parameter was assigned a value that is not one of its choices.
+    ///
+    /// The first associated string is the name of the aforementioned parameter.
+    /// The second associated string is the value that was assigned to it.
+    /// The associated vector of strings contains all the valid choices.
+    InvalidChoice(String, String, Vec<String>),
+
     /// Indicates that a subcommand was specified which the program does not recognize.
     ///
     /// The associated string is the name of the aforementioned subcommand.
@@ -70,6 +89,26 @@ pub enum ParsingError {
     ///
     /// The associated vector of strings contains all the missing required options.
     MissingRequiredParameters(Vec<String>),
+
+    /// Indicates that a quote in a command line was never closed.
+    ///
+    /// The associated numbers are the line and column of the aforementioned quote, both starting at one.
+    UnterminatedQuote(usize, usize),
+
+    /// Indicates that a command line ended in a backslash, which had nothing left to escape.
+    ///
+    /// The associated numbers are the line and column of the aforementioned backslash, both starting at one.
+    TrailingBackslash(usize, usize),
+
+    /// Indicates that `ParsedArgs::to_args` cannot produce an argument list that sets an option, because no enabled syntax can specify it.
+    ///
+    /// The associated string is the name of the aforementioned option.
+    UnrepresentableOption(String),
+
+    /// Indicates that `ParsedArgs::to_args` cannot produce an argument list with an unnamed parameter, because it would be mistaken for an option or a subcommand.
+    ///
+    /// The associated string is the aforementioned unnamed parameter.
+    UnrepresentableArgument(String),
 }
 
 impl Display for ParsingError {
@@ -89,6 +128,15 @@ impl Display for ParsingError {
             ParsingError::ParameterDuplicationAlias(name, alias) => write!(f, "parameter '{}' was set more than once; note that '{}' is an alias to '{0}'", name, alias),
             ParsingError::MissingArgument(name) => write!(f, "parameter '{}' is missing an argument", name),
             ParsingError::MissingArgumentAlias(name, alias) => write!(f, "parameter '{}' is missing an argument; note that '{}' is an alias to '{0}'", name, alias),
+            ParsingError::ToggledParameter(name) => write!(f, "parameter '{}' cannot be turned off", name),
+            ParsingError::ToggledParameterAlias(name, alias) => write!(f, "parameter '{}' cannot be turned off; note that '{}' is an alias to '{0}'", name, alias),
+            ParsingError::InvalidChoice(name, value, choices) => {
+                write!(f, "invalid value '{}' for parameter '{}'; possibilities:", value, name)?;
+                for choice in choices {
+                    write!(f, " '{}'", choice)?;
+                }
+                Ok(())
+            },
             ParsingError::UnrecognizedSubcommand(name) => write!(f, "unrecognized subcommand '{}'", name),
             ParsingError::AmbiguousSubcommand(name, matches) => {
                 write!(f, "subcommand '{}' is ambiguous; possibilities:", name)?;
@@ -109,18 +157,22 @@ impl Display for ParsingError {
                     Ok(())
                 },
             },
+            ParsingError::UnterminatedQuote(line, column) => write!(f, "unterminated quote at line {}, column {}", line, column),
+            ParsingError::TrailingBackslash(line, column) => write!(f, "backslash at line {}, column {} has nothing to escape", line, column),
+            ParsingError::UnrepresentableOption(name) => write!(f, "option '{}' cannot be specified with any enabled syntax", name),
+            ParsingError::UnrepresentableArgument(value) => write!(f, "unnamed parameter '{}' would be mistaken for an option or a subcommand", value),
         }
     }
 }
 
 impl Debug for ParsingError {
     fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
-        (self as &Display).fmt(f)
+        (self as &dyn Display).fmt(f)
     }
 }
 
 impl Error for ParsingError {
-    fn cause(&self) -> Option<&Error> {
+    fn cause(&self) -> Option<&dyn Error> {
         None
     }
 }
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Error as FmtError;
+
+
+/// Describes something in an argument list that was accepted but should likely be changed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParsingWarning {
+    /// Indicates that a deprecated option or alias was specified.
+    ///
+    /// The associated string is the name of the aforementioned option or alias.
+    /// The associated optional string is the name of the option or alias that replaces it, if there is one.
+    Deprecated(String, Option<String>),
+
+    /// Indicates that an option was specified by an abbreviation of its name, which can become ambiguous once other options are added.
+    ///
+    /// The first associated string is the aforementioned abbreviation.
+    /// The second associated string is the name of the option or alias that it was completed to.
+    Abbreviated(String, String),
+}
+
+impl Display for ParsingWarning {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self {
+            ParsingWarning::Deprecated(name, None) => write!(f, "option '{}' is deprecated", name),
+            ParsingWarning::Deprecated(name, Some(replacement)) => write!(f, "option '{}' is deprecated; use '{}'", name, replacement),
+            ParsingWarning::Abbreviated(typed, name) => write!(f, "option '{}' is an abbreviation; use '{}'", typed, name),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::io::BufRead;
+use std::io::IsTerminal;
+use std::io::Stderr;
+use std::io::StdinLock;
+use std::io::Write;
+
+use parsing_error::ParsingError;
+use parsing_config::ParsingConfig;
+use parsed_args::ParsedArgs;
+use parse::Parser;
+use parse::check_choice;
+
+
+/// Asks the user for the values of required parameters that are missing from the argument list.
+pub trait Prompter {
+    /// Returns `true` if a user can answer prompts.
+    /// If `false` is returned, missing required parameters are reported as an error as usual.
+    fn is_interactive(&self) -> bool;
+
+    /// Asks for the value of the parameter with the `name`.
+    /// If `secret` is `true`, the answer should not be shown while it is typed.
+    ///
+    /// Returns `None` if no answer could be read.
+    fn prompt(&mut self, name: &str, secret: bool) -> Option<String>;
+
+    /// Reports to the user that an answer for the parameter with the `name` was rejected, after which it is asked for again.
+    fn reject(&mut self, name: &str, error: &ParsingError);
+}
+
+
+/// A `Prompter` that writes prompts to an output and reads answers line by line from an input.
+///
+/// Answers to secret prompts are only read if the input is the terminal of the process, see `IoPrompter::stdio`.
+/// Echo is then turned off through `stty` while the answer is typed, which requires a Unix system.
+/// In every other case secret prompts are refused, so the parameter is reported as missing instead of being shown.
+/// Implement `Prompter` with a terminal library to support hidden input elsewhere.
+pub struct IoPrompter<R, W> {
+    input: R,
+    output: W,
+    interactive: bool,
+    terminal: bool,
+}
+
+/// Turns the echo of the terminal of the process on or off.
+/// Returns `true` if it succeeded.
+#[cfg(unix)]
+fn set_echo(enabled: bool) -> bool {
+    use std::process::Command;
+    use std::process::Stdio;
+
+    Command::new("stty")
+        .arg(if enabled { "echo" } else { "-echo" })
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|x| x.success())
+}
+
+#[cfg(not(unix))]
+fn set_echo(_enabled: bool) -> bool {
+    false
+}
+
+impl IoPrompter<StdinLock<'static>, Stderr> {
+    /// Constructs and returns an `IoPrompter` object that reads from the standard input and writes to the standard error.
+    ///
+    /// It is interactive if the standard input is a terminal, in which case the answers to secret prompts are hidden.
+    pub fn stdio() -> Self {
+        let stdin = std::io::stdin();
+        let terminal = stdin.is_terminal();
+        Self {
+            input: stdin.lock(),
+            output: std::io::stderr(),
+            interactive: terminal,
+            terminal,
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> IoPrompter<R, W> {
+    /// Constructs and returns an `IoPrompter` object.
+    ///
+    /// If `interactive` is `false`, the prompter never prompts.
+    /// Pass whether the input is a terminal, to fall back to errors when the program is not run by a user.
+    ///
+    /// Secret prompts are always refused, since the `input` cannot be assumed to be a terminal.
+    pub fn new(input: R, output: W, interactive: bool) -> Self {
+        Self {
+            input,
+            output,
+            interactive,
+            terminal: false,
+        }
+    }
+
+    /// Writes the prompt for the parameter with the `name` and reads the answer.
+    fn read_answer(&mut self, name: &str) -> Option<String> {
+        write!(self.output, "{}: ", name).ok()?;
+        self.output.flush().ok()?;
+        let mut answer = String::new();
+        if self.input.read_line(&mut answer).ok()? == 0 {
+            return None;
+        }
+        let len = answer.trim_end_matches(['\n', '\r']).len();
+        answer.truncate(len);
+        Some(answer)
+    }
+}
+
+impl<R: BufRead, W: Write> Prompter for IoPrompter<R, W> {
+    fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    fn prompt(&mut self, name: &str, secret: bool) -> Option<String> {
+        if !secret {
+            return self.read_answer(name);
+        }
+        if !self.terminal || !set_echo(false) {
+            return None;
+        }
+        let answer = self.read_answer(name);
+        set_echo(true);
+        // The newline typed after the answer was not echoed either.
+        let _ = writeln!(self.output);
+        answer
+    }
+
+    fn reject(&mut self, _name: &str, error: &ParsingError) {
+        let _ = writeln!(self.output, "{}", error);
+    }
+}
+
+
+/// Parses the argument list according to the provided configuration, and asks for the values of missing required parameters.
+/// The result is either an error or the parsed arguments.
+///
+/// Required parameters that are missing are prompted for in alphabetical order.
+/// Answers that are not one of the parameter's choices are rejected and asked for again.
+/// If the `prompter` is not interactive, or if it cannot read an answer, the parameters that are still missing are reported as an error.
+///
+/// Note that the argument list passed to this function cannot contain non-unicode characters.
+pub fn parse_with_prompter<I: Iterator<Item=String>, P: Prompter>(args: I, config: &ParsingConfig, prompter: &mut P) -> Result<ParsedArgs, ParsingError> {
+    let mut parser = Parser::new(args, config);
+    let mut parsed_args = ParsedArgs::new(std::mem::take(&mut parser.name));
+    for event in parser {
+        match event {
+            Ok(event) => parsed_args.apply(event, config),
+            Err(ParsingError::MissingRequiredParameters(mut names)) if prompter.is_interactive() => {
+                names.sort_unstable();
+                for (index, name) in names.iter().enumerate() {
+                    let value = loop {
+                        let answer = match prompter.prompt(name, config.secrets.contains(name)) {
+                            Some(answer) => answer,
+                            None => return Err(ParsingError::MissingRequiredParameters(names[index..].to_vec())),
+                        };
+                        match check_choice(config, name, answer) {
+                            Ok(value) => break value,
+                            Err(error) => prompter.reject(name, &error),
+                        }
+                    };
+                    parsed_args.params.insert(name.to_string(), value);
+                }
+            },
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(parsed_args)
+}
@@ -0,0 +1,84 @@
+use parsing_error::ParsingError;
+use parsing_config::ParsingConfig;
+use parsed_args::ParsedArgs;
+use parse::parse;
+
+
+/// Splits a command line into words the way a POSIX shell does, without performing any expansions.
+///
+/// Words are separated by unquoted whitespace.
+/// Single quotes preserve everything up to the next single quote.
+/// Double quotes preserve everything up to the next unescaped double quote, in which a backslash only escapes a double quote, a backslash, a dollar sign, a backquote or a newline.
+/// Outside of quotes a backslash preserves the next character, and a backslash followed by a newline joins two lines.
+/// A number sign at the start of a word starts a comment, which lasts until the end of the line.
+pub(crate) fn tokenize(line: &str) -> Result<Vec<String>, ParsingError> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut position = (1, 0);
+    let mut chars = line.chars().map(|x| {
+        position = if x == '\n' { (position.0 + 1, 0) } else { (position.0, position.1 + 1) };
+        (x, position)
+    }).peekable();
+    while let Some((value, (line, column))) = chars.next() {
+        match value {
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some(('\'', _)) => break,
+                        Some((value, _)) => word.push(value),
+                        None => return Err(ParsingError::UnterminatedQuote(line, column)),
+                    }
+                }
+            },
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some(('"', _)) => break,
+                        Some(('\\', (escape_line, escape_column))) => match chars.next() {
+                            Some(('\n', _)) => {},
+                            Some((value, _)) if "\"\\$`".contains(value) => word.push(value),
+                            Some((value, _)) => {
+                                word.push('\\');
+                                word.push(value);
+                            },
+                            None => return Err(ParsingError::TrailingBackslash(escape_line, escape_column)),
+                        },
+                        Some((value, _)) => word.push(value),
+                        None => return Err(ParsingError::UnterminatedQuote(line, column)),
+                    }
+                }
+            },
+            '\\' => match chars.next() {
+                Some(('\n', _)) => {},
+                Some((value, _)) => word.get_or_insert_with(String::new).push(value),
+                None => return Err(ParsingError::TrailingBackslash(line, column)),
+            },
+            '#' if word.is_none() => {
+                while chars.next_if(|x| x.0 != '\n').is_some() {}
+            },
+            value if value.is_whitespace() => {
+                if let Some(word) = word.take() {
+                    words.push(word);
+                }
+            },
+            value => word.get_or_insert_with(String::new).push(value),
+        }
+    }
+    if let Some(word) = word {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+
+/// Splits the command line into words like a POSIX shell and parses them according to the provided configuration.
+/// The result is either an error or the parsed arguments.
+///
+/// The first word is interpreted as the name of the command, like the first argument passed to `parse`.
+/// Quotes, backslashes and comments are handled like a POSIX shell does, but no expansions are performed.
+/// Errors in the command line itself are reported with the line and column at which they were found, both starting at one.
+pub fn parse_str(line: &str, config: &ParsingConfig) -> Result<ParsedArgs, ParsingError> {
+    parse(tokenize(line)?.into_iter(), config)
+}
@@ -0,0 +1,421 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Error as FmtError;
+
+use parsing_config::ParsingConfig;
+
+
+/// Returned when a usage text cannot be turned into a `ParsingConfig`.
+#[derive(PartialEq, Eq)]
+pub struct UsageError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl UsageError {
+    fn new(line: usize, column: usize, message: String) -> Self {
+        Self {
+            line,
+            column,
+            message,
+        }
+    }
+
+    /// Returns the line on which the error was found, starting at one.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column at which the error was found, starting at one.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns a description of the error.
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Debug for UsageError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        (self as &dyn Display).fmt(f)
+    }
+}
+
+impl Error for UsageError {
+    fn cause(&self) -> Option<&dyn Error> {
+        None
+    }
+}
+
+
+struct Token<'a> {
+    text: &'a str,
+    column: usize,
+}
+
+struct OptSpec {
+    name: String,
+    aliases: Vec<String>,
+    takes_arg: bool,
+}
+
+struct Group {
+    bracket: char,
+    column: usize,
+    alternatives: bool,
+    command_seen_before: bool,
+    command_seen: bool,
+    mentions: Vec<usize>,
+}
+
+struct UsageParser {
+    specs: Vec<OptSpec>,
+    names: HashMap<String, usize>,
+    subcmds: Vec<(String, usize, usize)>,
+}
+
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let mut column = 0;
+    for (index, line_char) in line.char_indices() {
+        column += 1;
+        let special = line_char == '[' || line_char == ']' || line_char == '(' || line_char == ')' || line_char == '|';
+        if line_char.is_whitespace() || special {
+            if let Some((start_index, start_column)) = start.take() {
+                tokens.push(Token { text: &line[start_index..index], column: start_column });
+            }
+            if special {
+                tokens.push(Token { text: &line[index..index+1], column });
+            }
+        } else if start.is_none() {
+            start = Some((index, column));
+        }
+    }
+    if let Some((start_index, start_column)) = start {
+        tokens.push(Token { text: &line[start_index..], column: start_column });
+    }
+
+    let mut split_tokens = Vec::new();
+    for token in tokens {
+        if token.text.len() > 3 && token.text.ends_with("...") {
+            let length = token.text.len() - 3;
+            split_tokens.push(Token { text: &token.text[..length], column: token.column });
+            split_tokens.push(Token { text: "...", column: token.column + token.text[..length].chars().count() });
+        } else {
+            split_tokens.push(token);
+        }
+    }
+    split_tokens
+}
+
+fn is_argument(text: &str) -> bool {
+    (text.starts_with('<') && text.ends_with('>')) || (text.chars().any(|x| x.is_uppercase()) && !text.chars().any(|x| x.is_lowercase()))
+}
+
+fn column_of(line: &str, part: &str) -> usize {
+    line[..part.as_ptr() as usize - line.as_ptr() as usize].chars().count() + 1
+}
+
+impl UsageParser {
+    fn lookup(&mut self, name: &str, takes_arg: Option<bool>, line: usize, column: usize) -> Result<usize, UsageError> {
+        if !ParsingConfig::is_valid_name(name) || name.is_empty() {
+            return Err(UsageError::new(line, column, format!("invalid option name '{}'", name)));
+        }
+        match self.names.get(name) {
+            Some(&index) => {
+                if let Some(takes_arg) = takes_arg {
+                    if takes_arg != self.specs[index].takes_arg {
+                        return Err(UsageError::new(line, column, format!("option '{}' is used both with and without an argument", name)));
+                    }
+                }
+                Ok(index)
+            },
+            None => {
+                self.specs.push(OptSpec {
+                    name: name.to_string(),
+                    aliases: Vec::new(),
+                    takes_arg: takes_arg.unwrap_or(false),
+                });
+                self.names.insert(name.to_string(), self.specs.len() - 1);
+                Ok(self.specs.len() - 1)
+            },
+        }
+    }
+
+    fn parse_option_line(&mut self, text: &str, line: usize) -> Result<(), UsageError> {
+        let trimmed = text.trim_start();
+        let spec = match trimmed.find("  ").into_iter().chain(trimmed.find('\t')).min() {
+            Some(end) => &trimmed[..end],
+            None => trimmed,
+        };
+
+        let mut names = Vec::new();
+        let mut takes_arg = false;
+        for word in spec.split(|x: char| x == ',' || x.is_whitespace()).filter(|x| !x.is_empty()) {
+            let column = column_of(text, word);
+            if let Some(long) = word.strip_prefix("--") {
+                let name = match long.find('=') {
+                    Some(eq_index) => {
+                        takes_arg = true;
+                        &long[..eq_index]
+                    },
+                    None => long,
+                };
+                names.push((name, column, true));
+            } else if let Some(short) = word.strip_prefix('-') {
+                let name = match short.chars().next() {
+                    Some(short_char) => &short[..short_char.len_utf8()],
+                    None => return Err(UsageError::new(line, column, String::from("expected an option name after '-'"))),
+                };
+                if short.len() > name.len() {
+                    takes_arg = true;
+                }
+                names.push((name, column, false));
+            } else if is_argument(word) {
+                takes_arg = true;
+            } else {
+                return Err(UsageError::new(line, column, format!("unexpected '{}' in option description", word)));
+            }
+        }
+
+        let canonical = names.iter().position(|x| x.2).unwrap_or(0);
+        let (name, column, _) = names[canonical];
+        if !ParsingConfig::is_valid_name(name) || name.is_empty() {
+            return Err(UsageError::new(line, column, format!("invalid option name '{}'", name)));
+        }
+        if self.names.contains_key(name) {
+            return Err(UsageError::new(line, column, format!("option '{}' is described more than once", name)));
+        }
+        let index = self.lookup(name, Some(takes_arg), line, column)?;
+        for (i, &(alias, column, _)) in names.iter().enumerate() {
+            if i == canonical {
+                continue;
+            }
+            if !ParsingConfig::is_valid_name(alias) || alias.is_empty() {
+                return Err(UsageError::new(line, column, format!("invalid option name '{}'", alias)));
+            }
+            if self.names.contains_key(alias) {
+                return Err(UsageError::new(line, column, format!("option '{}' is described more than once", alias)));
+            }
+            self.names.insert(alias.to_string(), index);
+            self.specs[index].aliases.push(alias.to_string());
+        }
+        Ok(())
+    }
+
+    /// Interprets one usage pattern.
+    /// Returns the options that are required by it and whether it requires a subcommand.
+    fn parse_pattern(&mut self, text: &str, line: usize, skip_header: bool) -> Result<(HashSet<usize>, bool), UsageError> {
+        let tokens = tokenize(text);
+        let mut tokens = tokens.iter().peekable();
+        if skip_header {
+            tokens.next();
+        }
+        if tokens.next().is_none() {
+            return Err(UsageError::new(line, text.chars().count() + 1, String::from("expected the name of the program")));
+        }
+
+        let mut stack = vec![Group {
+            bracket: ' ',
+            column: 0,
+            alternatives: false,
+            command_seen_before: false,
+            command_seen: false,
+            mentions: Vec::new(),
+        }];
+        let mut requires_command = false;
+
+        while let Some(token) = tokens.next() {
+            let command_seen = stack.last().unwrap().command_seen;
+            match token.text {
+                "[" | "(" => stack.push(Group {
+                    bracket: token.text.chars().next().unwrap(),
+                    column: token.column,
+                    alternatives: false,
+                    command_seen_before: command_seen,
+                    command_seen,
+                    mentions: Vec::new(),
+                }),
+                "]" | ")" => {
+                    let expected = if token.text == "]" { '[' } else { '(' };
+                    if stack.len() == 1 || stack.last().unwrap().bracket != expected {
+                        return Err(UsageError::new(line, token.column, format!("unexpected '{}'", token.text)));
+                    }
+                    let group = stack.pop().unwrap();
+                    let parent = stack.last_mut().unwrap();
+                    parent.command_seen |= group.command_seen;
+                    if group.bracket == '(' && !group.alternatives {
+                        parent.mentions.extend(group.mentions);
+                    }
+                },
+                "|" => {
+                    let group = stack.last_mut().unwrap();
+                    group.alternatives = true;
+                    group.command_seen = group.command_seen_before;
+                },
+                "..." | "--" | "-" => {},
+                "options" if stack.len() > 1 && stack.last().unwrap().bracket == '[' => {},
+                _ if command_seen => {},
+                text if text.starts_with("--") => {
+                    let (name, takes_arg) = match text.find('=') {
+                        Some(eq_index) => (&text[2..eq_index], Some(true)),
+                        None => (&text[2..], None),
+                    };
+                    let index = self.lookup(name, takes_arg, line, token.column)?;
+                    if takes_arg.is_none() && self.specs[index].takes_arg && tokens.peek().is_some_and(|x| is_argument(x.text)) {
+                        tokens.next();
+                    }
+                    stack.last_mut().unwrap().mentions.push(index);
+                },
+                text if text.starts_with('-') => {
+                    let mut chars = text[1..].char_indices();
+                    while let Some((char_index, short_char)) = chars.next() {
+                        let name = &text[1+char_index..1+char_index+short_char.len_utf8()];
+                        let index = self.lookup(name, None, line, token.column + 1 + char_index)?;
+                        stack.last_mut().unwrap().mentions.push(index);
+                        if self.specs[index].takes_arg {
+                            if chars.next().is_none() && tokens.peek().is_some_and(|x| is_argument(x.text)) {
+                                tokens.next();
+                            }
+                            break;
+                        }
+                    }
+                },
+                text if is_argument(text) => {},
+                text => {
+                    if !ParsingConfig::is_valid_name(text) {
+                        return Err(UsageError::new(line, token.column, format!("invalid subcommand name '{}'", text)));
+                    }
+                    if !self.subcmds.iter().any(|x| x.0 == text) {
+                        self.subcmds.push((text.to_string(), line, token.column));
+                    }
+                    if !stack.iter().any(|x| x.bracket == '[') {
+                        requires_command = true;
+                    }
+                    stack.last_mut().unwrap().command_seen = true;
+                },
+            }
+        }
+
+        if stack.len() > 1 {
+            let group = stack.last().unwrap();
+            return Err(UsageError::new(line, group.column, format!("unclosed '{}'", group.bracket)));
+        }
+        let group = stack.pop().unwrap();
+        let required = if group.alternatives {
+            HashSet::new()
+        } else {
+            group.mentions.into_iter().collect()
+        };
+        Ok((required, requires_command))
+    }
+}
+
+impl ParsingConfig {
+    /// Constructs a `ParsingConfig` object from a usage text in the style of docopt.
+    ///
+    /// The usage text must contain a section that starts with "Usage:" and ends at the first empty line.
+    /// Each line in it is a pattern that starts with the name of the program, followed by options, arguments and commands.
+    /// Square brackets mark optional elements, parentheses group elements and a vertical bar separates alternatives.
+    ///
+    /// Options are described in sections whose first line contains "Options:", such as "Options:" or "Global options:".
+    /// Such a section continues with the indented lines that follow, and each of them that starts with a hyphen describes an option, as follows:
+    ///
+    /// > -o FILE, --out=FILE  Description of the option.
+    ///
+    /// The description starts after two consecutive spaces.
+    /// The long name becomes the name of the option, and any other names become aliases to it.
+    /// An option that is described with an argument becomes a parameter, otherwise it becomes a flag.
+    /// Options that are only mentioned in the usage section become parameters if they are written as "--NAME=ARG", and flags otherwise.
+    ///
+    /// A parameter is required if it is mentioned outside of square brackets and alternatives in every pattern.
+    /// The first command in a pattern becomes a subcommand, and everything after it is left to that subcommand.
+    /// A subcommand is required if every pattern contains a command outside of square brackets.
+    /// Arguments such as "<input>" or "FILE" are accepted as unnamed parameters and are not validated.
+    ///
+    /// All other features are configured as in `ParsingConfig::new`.
+    pub fn from_usage(text: &str) -> Result<Self, UsageError> {
+        let lines: Vec<&str> = text.lines().collect();
+        let start = match lines.iter().position(|x| x.trim_start().to_lowercase().starts_with("usage:")) {
+            Some(start) => start,
+            None => return Err(UsageError::new(1, 1, String::from("missing usage section"))),
+        };
+        let end = lines[start..].iter().position(|x| x.trim().is_empty()).map_or(lines.len(), |x| start + x);
+
+        let mut parser = UsageParser {
+            specs: Vec::new(),
+            names: HashMap::new(),
+            subcmds: Vec::new(),
+        };
+        // Other lines are prose, which may well start with a hyphen, for example as a bullet point.
+        let mut in_options = false;
+        for (i, line) in lines.iter().enumerate() {
+            if i >= start && i < end {
+                in_options = false;
+            } else if line.to_lowercase().contains("options:") {
+                in_options = true;
+            } else if !line.starts_with([' ', '\t']) {
+                in_options = false;
+            } else if in_options && line.trim_start().starts_with('-') {
+                parser.parse_option_line(line, i + 1)?;
+            }
+        }
+
+        let mut required: Option<HashSet<usize>> = None;
+        let mut subcmd_required = true;
+        let mut patterns = 0;
+        for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+            let skip_header = i == start;
+            if skip_header && line.trim().len() == "usage:".len() {
+                continue;
+            }
+            let (pattern_required, requires_command) = parser.parse_pattern(line, i + 1, skip_header)?;
+            required = Some(match required {
+                Some(required) => required.intersection(&pattern_required).cloned().collect(),
+                None => pattern_required,
+            });
+            subcmd_required &= requires_command;
+            patterns += 1;
+        }
+        if patterns == 0 {
+            return Err(UsageError::new(start + 1, 1, String::from("usage section contains no patterns")));
+        }
+        let required = required.unwrap_or_default();
+
+        let mut config = Self::new();
+        for (index, spec) in parser.specs.iter().enumerate() {
+            if spec.takes_arg {
+                config.add_param(spec.name.clone(), required.contains(&index));
+            } else {
+                config.add_flag(spec.name.clone());
+            }
+        }
+        for spec in parser.specs.iter() {
+            for alias in spec.aliases.iter() {
+                config.add_alias(alias.clone(), spec.name.clone());
+            }
+        }
+        for (name, line, column) in parser.subcmds {
+            if parser.names.contains_key(&name) {
+                return Err(UsageError::new(line, column, format!("subcommand '{}' has the same name as an option", name)));
+            }
+            config.add_subcommand(name);
+        }
+        config.set_subcommand_required(subcmd_required && !config.subcmds.is_empty());
+        Ok(config)
+    }
+}
@@ -0,0 +1,115 @@
+extern crate clargs;
+
+#[test]
+fn builder_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose").short('v')
+        .param("out").short('o').required()
+        .optional_param("color", "always").alias("colour")
+        .subcommand("build")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "-v", "-o", "file", "--colour", "build", "--release"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("out"), Some("file"));
+    assert_eq!(result.get_param("color"), Some("always"));
+    assert_eq!(result.get_subcommand(), Some("build"));
+    assert_eq!(result.subcommand_args(), &vec![String::from("build"), String::from("--release")]);
+
+    let args = ["clargs", "-v"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("out")]));
+}
+
+#[test]
+fn builder_test2() {
+    let config = clargs::ParsingConfig::builder_all_disabled()
+        .slash_syntax(true)
+        .slash_separator('=')
+        .flag("verbose")
+        .param(String::from("out"))
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "/verbose", "/out=file", "--verbose"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("out"), Some("file"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("--verbose")]);
+}
+
+#[test]
+fn builder_test3() {
+    let result = clargs::ParsingConfig::builder()
+        .flag("verbose").short('v')
+        .param("version").short('v')
+        .flag("a=b")
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::NameTaken(String::from("v"))));
+
+    let result = clargs::ParsingConfig::builder()
+        .subcommand("")
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::InvalidName(String::new())));
+}
+
+#[test]
+fn builder_test4() {
+    let result = clargs::ParsingConfig::builder()
+        .subcommand("build")
+        .short('b')
+        .flag("verbose")
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::MisplacedModifier(String::from("short"))));
+
+    let result = clargs::ParsingConfig::builder()
+        .exact()
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::MisplacedModifier(String::from("exact"))));
+}
+
+#[test]
+fn builder_test5() {
+    let result = clargs::ParsingConfig::builder()
+        .flag("verbose")
+        .required()
+        .build();
+    let error = result.err().unwrap();
+    assert_eq!(error, clargs::ConfigError::MisplacedModifier(String::from("required")));
+    assert_eq!(error.to_string(), "'required' does not follow a name it can apply to");
+
+    let result = clargs::ParsingConfig::builder()
+        .optional_param("color", "always").short('c').choices(vec!["always", "never"])
+        .flag("verbose").secret()
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::MisplacedModifier(String::from("secret"))));
+}
+
+#[test]
+fn builder_test6() {
+    let result = clargs::ParsingConfig::builder()
+        .slash_syntax(true)
+        .slash_separator('-')
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::InvalidSetting(String::from("slash separator"))));
+
+    let result = clargs::ParsingConfig::builder()
+        .flag("verbose")
+        .flag("Verbose")
+        .case_insensitive(true)
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::NameTakenIgnoringCase(String::from("verbose"), String::from("Verbose"))));
+
+    let result = clargs::ParsingConfig::builder()
+        .flag("build")
+        .subcommand("Build")
+        .flag("v")
+        .flag("V")
+        .case_insensitive(true)
+        .build();
+    assert_eq!(result.err(), Some(clargs::ConfigError::NameTakenIgnoringCase(String::from("Build"), String::from("build"))));
+}
@@ -0,0 +1,99 @@
+extern crate clargs;
+
+#[test]
+fn case_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .case_insensitive(true)
+        .flag("verbose")
+        .param("out").alias("output")
+        .subcommand("build")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--Verbose", "--OUTPUT=file", "BUILD", "--release"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("out"), Some("file"));
+    assert_eq!(result.get_subcommand(), Some("build"));
+
+    let events = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(events[1].alias(), Some("output"));
+    assert_eq!(events[1].typed(), Some("OUTPUT"));
+}
+
+#[test]
+fn case_test2() {
+    let config = clargs::ParsingConfig::builder()
+        .case_insensitive(true)
+        .flag("verbose")
+        .flag("version")
+        .flag("Ver")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--VER", "--verB"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.has_flag("Ver"));
+    assert!(result.has_flag("verbose"));
+    assert!(!result.has_flag("version"));
+
+    let args = ["clargs", "--VE"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AmbiguousOption(String::from("VE"), vec![String::from("Ver"), String::from("verbose"), String::from("version")]));
+}
+
+#[test]
+fn case_test3() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_case_insensitive(true);
+    config.set_option_completion(false);
+    config.set_subcommand_index(true);
+    config.set_subcommand_required(true);
+    config.add_flag(String::from("v"));
+    config.add_flag(String::from("V"));
+    config.add_flag(String::from("verbose"));
+    config.add_subcommand(String::from("build"));
+
+    let args = ["clargs", "-V", "--VERBOSE", "Build"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.has_flag("V"));
+    assert!(!result.has_flag("v"));
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_subcommand(), Some("build"));
+
+    let args = ["clargs", "--verb"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("verb")));
+}
+
+#[test]
+fn case_test4() {
+    let config = clargs::ParsingConfig::builder()
+        .case_insensitive(true)
+        .param("color").choices(vec!["always", "never", "auto"])
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--COLOR=Never"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("color"), Some("never"));
+}
+
+#[test]
+fn case_test5() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_flag(String::from("Verbose"));
+    config.add_flag(String::from("v"));
+    config.add_subcommand(String::from("Build"));
+    config.set_case_insensitive(true);
+
+    assert_eq!(config.try_add_flag(String::from("V")), Ok(()));
+    assert_eq!(config.try_add_subcommand(String::from("build")), Err(clargs::ConfigError::NameTakenIgnoringCase(String::from("build"), String::from("Build"))));
+    assert_eq!(config.try_add_alias(String::from("VERBOSE"), String::from("v")), Err(clargs::ConfigError::NameTakenIgnoringCase(String::from("VERBOSE"), String::from("Verbose"))));
+
+    let lints = config.lint();
+    assert_eq!(lints, vec![clargs::ConfigLint::CaseConflict(String::from("Verbose"), String::from("verbose"))]);
+    assert_eq!(lints[0].to_string(), "names 'Verbose' and 'verbose' differ only in case");
+}
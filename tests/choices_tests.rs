@@ -0,0 +1,42 @@
+extern crate clargs;
+
+#[test]
+fn choices_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .param("color").choices(vec!["always", "never", "auto"])
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--color", "never"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("color"), Some("never"));
+
+    let args = ["clargs", "--color", "Never"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::InvalidChoice(String::from("color"), String::from("Never"), vec![String::from("always"), String::from("never"), String::from("auto")]));
+    assert_eq!(result.to_string(), "invalid value 'Never' for parameter 'color'; possibilities: 'always' 'never' 'auto'");
+}
+
+#[test]
+fn choices_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_param(String::from("level"), false);
+    config.add_alias(String::from("l"), String::from("level"));
+    config.set_choices(String::from("level"), vec![String::from("1"), String::from("2")]);
+
+    let args = ["clargs", "-l2"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("level"), Some("2"));
+
+    let args = ["clargs", "--level=3"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::InvalidChoice(String::from("level"), String::from("3"), vec![String::from("1"), String::from("2")]));
+}
+
+#[test]
+#[should_panic]
+fn choices_test3() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.set_choices(String::from("verbose"), vec![String::from("yes")]);
+}
@@ -0,0 +1,66 @@
+extern crate clargs;
+
+fn request(config: &clargs::ParsingConfig, shell: Option<&str>, args: &[&str]) -> (bool, String) {
+    let mut output = Vec::new();
+    let handled = clargs::complete_env(config, shell, args.iter().map(|x| x.to_string()), &mut output).unwrap();
+    (handled, String::from_utf8(output).unwrap())
+}
+
+fn config() -> clargs::ParsingConfig {
+    clargs::ParsingConfig::builder()
+        .flag("verbose").description("Print more output")
+        .param("branch").value_completer(|_| vec![String::from("main"), String::from("master"), String::from("dev")])
+        .param("color").choices(vec!["always", "never"])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn complete_env_test1() {
+    let config = config();
+
+    assert_eq!(request(&config, None, &["tool", "--", "tool --"]), (false, String::new()));
+    assert_eq!(request(&config, Some("bash"), &["tool", "--", "tool --v"]), (true, String::from("--verbose\n")));
+    assert_eq!(request(&config, Some("bash"), &["tool", "--", "tool --branch ma"]), (true, String::from("main\nmaster\n")));
+    assert_eq!(request(&config, Some("bash"), &["tool", "--", "tool --color=n"]), (true, String::from("never\n")));
+    assert_eq!(request(&config, Some("bash"), &["tool", "--", "tool --branch "]), (true, String::from("dev\nmain\nmaster\n")));
+}
+
+#[test]
+fn complete_env_test2() {
+    let config = config();
+
+    assert_eq!(request(&config, Some("zsh"), &["tool", "--", "tool --"]), (true, String::from("--branch\n--color\n--verbose:Print more output\n")));
+    assert_eq!(request(&config, Some("fish"), &["tool", "--", "tool --ver"]), (true, String::from("--verbose\tPrint more output\n")));
+    assert_eq!(request(&config, Some("fish"), &["tool", "--", "tool --color=a"]), (true, String::from("--color=always\n")));
+}
+
+#[test]
+fn complete_env_test3() {
+    let config = config();
+
+    let (handled, script) = request(&config, Some("bash"), &["/usr/bin/my-tool"]);
+    assert!(handled);
+    assert!(script.contains("_clargs_my_tool()"));
+    assert!(script.contains("complete -o default -F _clargs_my_tool my-tool\n"));
+
+    let (_, script) = request(&config, Some("fish"), &["my-tool"]);
+    assert_eq!(script, "complete -c my-tool -f -a '(env COMPLETE=fish my-tool -- (commandline -cp))'\n");
+
+    let (_, script) = request(&config, Some("zsh"), &["my-tool"]);
+    assert!(script.starts_with("#compdef my-tool\n"));
+
+    assert_eq!(request(&config, Some("powershell"), &["my-tool"]), (true, String::new()));
+    assert_eq!(request(&config, Some("bash"), &["tool", "--", "tool 'unterminated"]), (true, String::new()));
+}
+
+#[test]
+fn complete_env_test4() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<clargs::ParsingConfig>();
+
+    let config = std::sync::Arc::new(config());
+    let shared = config.clone();
+    let output = std::thread::spawn(move || request(&shared, Some("bash"), &["tool", "--", "tool --branch d"])).join().unwrap();
+    assert_eq!(output, (true, String::from("dev\n")));
+}
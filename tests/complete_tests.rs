@@ -0,0 +1,93 @@
+extern crate clargs;
+
+fn values(candidates: &[clargs::Candidate]) -> Vec<&str> {
+    candidates.iter().map(|x| x.value()).collect()
+}
+
+fn config() -> clargs::ParsingConfig {
+    clargs::ParsingConfig::builder()
+        .flag("verbose").short('v').description("Print more output")
+        .param("color").short('c').choices(vec!["always", "auto", "never"]).description("When to use colors")
+        .param("out").short('o')
+        .flag("debug-internals").hidden()
+        .subcommand("build").description("Build the project")
+        .subcommand("bench")
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn complete_test1() {
+    let config = config();
+    let args = ["clargs"];
+
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "--");
+    assert_eq!(values(&candidates), vec!["--color", "--out", "--verbose"]);
+    assert_eq!(candidates[0].description(), Some("When to use colors"));
+    assert_eq!(candidates[0].kind(), clargs::CandidateKind::Param);
+    assert_eq!(candidates[2].kind(), clargs::CandidateKind::Flag);
+
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "--co");
+    assert_eq!(values(&candidates), vec!["--color"]);
+
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "--color=a");
+    assert_eq!(values(&candidates), vec!["--color=always", "--color=auto"]);
+    assert_eq!(candidates[0].kind(), clargs::CandidateKind::Value);
+}
+
+#[test]
+fn complete_test2() {
+    let config = config();
+
+    let args = ["clargs", "--color"];
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "n");
+    assert_eq!(values(&candidates), vec!["never"]);
+
+    let args = ["clargs", "-vc"];
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "");
+    assert_eq!(values(&candidates), vec!["always", "auto", "never"]);
+
+    let args = ["clargs", "--out"];
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "");
+    assert_eq!(values(&candidates), Vec::<&str>::new());
+}
+
+#[test]
+fn complete_test3() {
+    let config = config();
+    let args = ["clargs"];
+
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "-");
+    assert_eq!(values(&candidates), vec!["-c", "-o", "-v"]);
+    assert_eq!(candidates[2].description(), Some("Print more output"));
+
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "-v");
+    assert_eq!(values(&candidates), vec!["-vc", "-vo"]);
+
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "-cv");
+    assert_eq!(values(&candidates), Vec::<&str>::new());
+}
+
+#[test]
+fn complete_test4() {
+    let mut config = config();
+
+    let args = ["clargs", "-v"];
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "b");
+    assert_eq!(values(&candidates), vec!["bench", "build"]);
+    assert_eq!(candidates[1].description(), Some("Build the project"));
+    assert_eq!(candidates[1].kind(), clargs::CandidateKind::Subcommand);
+
+    config.set_subcommand_index(true);
+    config.set_subcommand_index_value(1);
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "b");
+    assert_eq!(values(&candidates), Vec::<&str>::new());
+
+    let args = ["clargs", "file"];
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "b");
+    assert_eq!(values(&candidates), vec!["bench", "build"]);
+
+    let args = ["clargs", "file", "build"];
+    let candidates = clargs::complete(&config, args.iter().map(|x| x.to_string()), "--");
+    assert_eq!(values(&candidates), Vec::<&str>::new());
+}
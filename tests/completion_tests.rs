@@ -0,0 +1,79 @@
+extern crate clargs;
+
+#[test]
+fn completion_test1() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_alias(String::from("verb"), String::from("verbose"));
+    config.add_alias(String::from("verbosity"), String::from("verbose"));
+    config.add_param(String::from("output"), false);
+    config.add_alias(String::from("out-file"), String::from("output"));
+    config.add_alias(String::from("out-path"), String::from("output"));
+
+    let args = ["clargs", "--ver", "--out", "file"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("output"), Some("file"));
+
+    let events = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(events[0].alias(), None);
+    assert_eq!(events[1].alias(), None);
+
+    let args = ["clargs", "--out-"];
+    let events = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(events, clargs::ParsingError::MissingArgumentAlias(String::from("output"), String::from("out-file")));
+}
+
+#[test]
+fn completion_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_flag(String::from("version"));
+    config.add_alias(String::from("vers"), String::from("version"));
+
+    let args = ["clargs", "--ver"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AmbiguousOption(String::from("ver"), vec![String::from("verbose"), String::from("version")]));
+}
+
+#[test]
+fn completion_test3() {
+    let config = clargs::ParsingConfig::builder()
+        .option_completion_min_length(3)
+        .flag("verbose")
+        .flag("q")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--ver", "--q"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.has_flag("verbose"));
+    assert!(result.has_flag("q"));
+
+    let args = ["clargs", "--ve"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("ve")));
+}
+
+#[test]
+fn completion_test4() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("delete-all").exact()
+        .flag("delete")
+        .flag("dry-run")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--del", "--delete-all"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.has_flag("delete"));
+    assert!(result.has_flag("delete-all"));
+
+    let args = ["clargs", "--delete-a"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("delete-a")));
+
+    let args = ["clargs", "--d"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AmbiguousOption(String::from("d"), vec![String::from("delete"), String::from("dry-run")]));
+}
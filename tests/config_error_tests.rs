@@ -0,0 +1,51 @@
+extern crate clargs;
+
+#[test]
+fn config_error_test1() {
+    let mut config = clargs::ParsingConfig::new();
+    assert_eq!(config.try_add_flag(String::from("verbose")), Ok(()));
+    assert_eq!(config.try_add_param(String::from("out"), true), Ok(()));
+    assert_eq!(config.try_add_optional_param(String::from("color"), String::from("always")), Ok(()));
+    assert_eq!(config.try_add_alias(String::from("v"), String::from("verbose")), Ok(()));
+    assert_eq!(config.try_add_subcommand(String::from("build")), Ok(()));
+
+    let args = ["clargs", "-v", "--out", "file", "build"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("out"), Some("file"));
+    assert_eq!(result.get_subcommand(), Some("build"));
+}
+
+#[test]
+fn config_error_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_subcommand(String::from("build"));
+
+    assert_eq!(config.try_add_flag(String::from("verbose")), Err(clargs::ConfigError::NameTaken(String::from("verbose"))));
+    assert_eq!(config.try_add_param(String::from("build"), false), Err(clargs::ConfigError::NameTaken(String::from("build"))));
+    assert_eq!(config.try_add_subcommand(String::from("verbose")), Err(clargs::ConfigError::NameTaken(String::from("verbose"))));
+}
+
+#[test]
+fn config_error_test3() {
+    let mut config = clargs::ParsingConfig::new();
+
+    assert_eq!(config.try_add_flag(String::from("a=b")), Err(clargs::ConfigError::InvalidName(String::from("a=b"))));
+    assert_eq!(config.try_add_optional_param(String::from("-x"), String::from("1")), Err(clargs::ConfigError::InvalidName(String::from("-x"))));
+    assert_eq!(config.try_add_subcommand(String::new()), Err(clargs::ConfigError::InvalidName(String::new())));
+}
+
+#[test]
+fn config_error_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+
+    assert_eq!(config.try_add_alias(String::from("v"), String::from("v")), Err(clargs::ConfigError::AliasSelfReference(String::from("v"))));
+    assert_eq!(config.try_add_alias(String::from("q"), String::from("quiet")), Err(clargs::ConfigError::AliasTargetMissing(String::from("q"), String::from("quiet"))));
+    assert_eq!(config.try_add_alias(String::from("verbose"), String::from("v")), Err(clargs::ConfigError::NameTaken(String::from("verbose"))));
+
+    let error = clargs::ConfigError::AliasTargetMissing(String::from("q"), String::from("quiet"));
+    assert_eq!(error.to_string(), "alias 'q' points to 'quiet', which does not exist");
+}
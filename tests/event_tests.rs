@@ -0,0 +1,71 @@
+extern crate clargs;
+
+use clargs::EventKind;
+
+#[test]
+fn event_test1() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_param(String::from("name"), false);
+    config.add_alias(String::from("v"), String::from("verbose"));
+
+    let args = ["clargs", "file1", "-v", "--name", "value", "file2", "--", "--verbose"];
+    let result = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let kinds: Vec<&EventKind> = result.iter().map(|x| x.kind()).collect();
+
+    assert_eq!(kinds, vec![
+        &EventKind::Unnamed(String::from("file1")),
+        &EventKind::Flag(String::from("verbose")),
+        &EventKind::Param(String::from("name"), String::from("value")),
+        &EventKind::Unnamed(String::from("file2")),
+        &EventKind::Marker,
+        &EventKind::Unnamed(String::from("--verbose")),
+    ]);
+    assert_eq!(result.iter().map(|x| x.index()).collect::<Vec<usize>>(), vec![1, 2, 3, 5, 6, 7]);
+    assert_eq!(result[1].alias(), Some("v"));
+    assert_eq!(result[2].alias(), None);
+}
+
+#[test]
+fn event_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_parameter_duplication(true);
+    config.add_flag(String::from("f"));
+    config.add_param(String::from("p"), false);
+    config.add_param(String::from("q"), false);
+
+    let args = ["clargs", "-fpq", "value1", "value2", "-p", "value3"];
+    let result = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.len(), 4);
+    assert_eq!(result[0].kind(), &EventKind::Flag(String::from("f")));
+    assert_eq!(result[1].kind(), &EventKind::Param(String::from("p"), String::from("value1")));
+    assert_eq!(result[2].kind(), &EventKind::Param(String::from("q"), String::from("value2")));
+    assert_eq!(result[3].kind(), &EventKind::Param(String::from("p"), String::from("value3")));
+    assert_eq!(result.iter().map(|x| x.index()).collect::<Vec<usize>>(), vec![1, 1, 1, 4]);
+}
+
+#[test]
+fn event_test3() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.add_flag(String::from("v"));
+    config.add_subcommand(String::from("build"));
+
+    let args = ["clargs", "-v", "build", "-v", "target"];
+    let result = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[1].kind(), &EventKind::Subcommand(String::from("build"), vec![String::from("-v"), String::from("target")]));
+    assert_eq!(result[1].index(), 2);
+}
+
+#[test]
+fn event_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_param(String::from("name"), true);
+
+    let args = ["clargs", "file1"];
+    let result = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("name")]));
+}
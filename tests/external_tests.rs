@@ -0,0 +1,158 @@
+extern crate clargs;
+
+use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_dir(name: &str, executables: &[&str]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("clargs-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for executable in executables {
+        let path = dir.join(format!("{}{}", executable, std::env::consts::EXE_SUFFIX));
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
+    dir
+}
+
+fn search_path(dirs: &[&PathBuf]) -> OsString {
+    std::env::join_paths(dirs.iter()).unwrap()
+}
+
+#[test]
+fn external_test1() {
+    let dir = temp_dir("external1", &["tool-foo", "other-bar"]);
+    let externals = clargs::ExternalSubcommands::new("tool", &search_path(&[&dir]));
+
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_subcommand(String::from("build"));
+
+    let args = ["tool", "--verbose", "foo", "--force", "x"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).unwrap();
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_subcommand(), Some("foo"));
+    assert_eq!(result.subcommand_args(), &vec![String::from("foo"), String::from("--force"), String::from("x")]);
+    let external = result.external_subcommand().unwrap();
+    assert_eq!(external.name(), "foo");
+    assert_eq!(external.path(), dir.join(format!("tool-foo{}", std::env::consts::EXE_SUFFIX)).as_path());
+    assert_eq!(external.args(), &[String::from("--force"), String::from("x")]);
+
+    let args = ["tool", "build", "foo"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).unwrap();
+    assert_eq!(result.get_subcommand(), Some("build"));
+    assert!(result.external_subcommand().is_none());
+
+    let args = ["tool", "bar"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).unwrap();
+    assert_eq!(result.unnamed_params(), &vec![String::from("bar")]);
+    assert!(result.external_subcommand().is_none());
+
+    let args = ["tool", "foo"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.external_subcommand().is_none());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn external_test2() {
+    let dir = temp_dir("external2", &["tool-foo"]);
+    let externals = clargs::ExternalSubcommands::new("tool", &search_path(&[&dir]));
+
+    let mut config = clargs::ParsingConfig::new();
+    config.set_subcommand_index(true);
+    config.set_subcommand_required(true);
+    config.set_subcommand_completion(true);
+    config.add_subcommand(String::from("build"));
+    config.add_subcommand(String::from("bundle"));
+
+    let args = ["tool", "foo", "a"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).unwrap();
+    assert_eq!(result.external_subcommand().unwrap().args(), &[String::from("a")]);
+
+    let args = ["tool", "fo"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedSubcommand(String::from("fo")));
+
+    let args = ["tool", "b"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AmbiguousSubcommand(String::from("b"), vec![String::from("build"), String::from("bundle")]));
+
+    let args = ["tool"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredSubcommand);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn external_test3() {
+    let dir = temp_dir("external3", &["tool-foo"]);
+    let externals = clargs::ExternalSubcommands::new("tool", &search_path(&[&dir]));
+
+    let mut config = clargs::ParsingConfig::new();
+    config.set_subcommand_index(true);
+    config.set_subcommand_index_value(1);
+
+    let args = ["tool", "foo", "foo", "x"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).unwrap();
+    assert_eq!(result.unnamed_params(), &vec![String::from("foo")]);
+    assert_eq!(result.external_subcommand().unwrap().args(), &[String::from("x")]);
+
+    let args = ["tool", "foo", "bar", "foo"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).unwrap();
+    assert_eq!(result.unnamed_params(), &vec![String::from("foo"), String::from("bar"), String::from("foo")]);
+    assert!(result.external_subcommand().is_none());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn external_test4() {
+    let first = temp_dir("external4a", &["tool-foo", "tool-bar", "tool-", "other-baz"]);
+    let second = temp_dir("external4b", &["tool-foo", "tool-qux"]);
+    fs::create_dir(first.join("tool-dir")).unwrap();
+    let externals = clargs::ExternalSubcommands::new("tool", &search_path(&[&first, &second]));
+
+    assert_eq!(externals.list(), vec![String::from("bar"), String::from("foo"), String::from("qux")]);
+    assert_eq!(externals.find("foo"), Some(first.join(format!("tool-foo{}", std::env::consts::EXE_SUFFIX))));
+    assert_eq!(externals.find("qux"), Some(second.join(format!("tool-qux{}", std::env::consts::EXE_SUFFIX))));
+    assert_eq!(externals.find("dir"), None);
+    assert_eq!(externals.find(""), None);
+    assert_eq!(externals.find("../tool-foo"), None);
+
+    let empty = clargs::ExternalSubcommands::new("tool", &OsString::new());
+    assert!(empty.list().is_empty());
+    assert_eq!(empty.find("foo"), None);
+
+    fs::remove_dir_all(&first).unwrap();
+    fs::remove_dir_all(&second).unwrap();
+}
+
+#[test]
+fn external_test5() {
+    let dir = temp_dir("external5", &["tool-foo"]);
+    let externals = clargs::ExternalSubcommands::new("tool", &search_path(&[&dir]));
+
+    let config = clargs::ParsingConfig::builder()
+        .param("token").required().global()
+        .build()
+        .unwrap();
+
+    let args = ["tool", "foo", "x"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+
+    let args = ["tool", "--token", "abc", "foo", "x"];
+    let result = clargs::parse_with_externals(args.iter().map(|x| x.to_string()), &config, &externals).unwrap();
+    assert_eq!(result.get_param("token"), Some("abc"));
+    assert!(result.external_subcommand().is_some());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,164 @@
+extern crate clargs;
+
+#[test]
+fn global_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose").short('v').global()
+        .flag("dry-run")
+        .subcommand("build")
+        .build()
+        .unwrap();
+    let build_config = clargs::ParsingConfig::builder()
+        .flag("release")
+        .inherit_globals(&config)
+        .build()
+        .unwrap();
+
+    let args = ["tool", "-v", "build", "--release"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let build_result = result.parse_subcommand(&build_config).unwrap();
+    assert!(build_result.has_flag("verbose"));
+    assert!(build_result.has_flag("release"));
+
+    let args = ["tool", "build", "-v"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let build_result = result.parse_subcommand(&build_config).unwrap();
+    assert!(build_result.has_flag("verbose"));
+
+    let args = ["tool", "build", "--dry-run"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let build_result = result.parse_subcommand(&build_config).err().unwrap();
+    assert_eq!(build_result, clargs::ParsingError::UnrecognizedOption(String::from("dry-run")));
+}
+
+#[test]
+fn global_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_param(String::from("color"), true);
+    config.set_choices(String::from("color"), vec![String::from("auto"), String::from("never")]);
+    config.set_global(String::from("color"), true);
+    config.add_subcommand(String::from("remote"));
+
+    let mut remote_config = clargs::ParsingConfig::new();
+    remote_config.inherit_globals(&config);
+    remote_config.add_subcommand(String::from("add"));
+
+    let mut add_config = clargs::ParsingConfig::new();
+    add_config.inherit_globals(&remote_config);
+
+    let args = ["tool", "--color", "auto", "remote", "add", "--color", "never"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let mut remote_result = clargs::parse(result.subcommand_args().iter().cloned(), &remote_config).unwrap();
+    remote_result.merge_globals(&result, &remote_config);
+    assert_eq!(remote_result.get_param("color"), Some("auto"));
+    let mut add_result = clargs::parse(remote_result.subcommand_args().iter().cloned(), &add_config).unwrap();
+    add_result.merge_globals(&remote_result, &add_config);
+    assert_eq!(add_result.get_param("color"), Some("never"));
+
+    let args = ["add", "--color", "always"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &add_config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::InvalidChoice(String::from("color"), String::from("always"), vec![String::from("auto"), String::from("never")]));
+}
+
+#[test]
+fn global_test3() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("quiet"));
+    config.add_alias(String::from("q"), String::from("quiet"));
+    config.add_param(String::from("output"), false);
+    config.set_global(String::from("quiet"), true);
+    config.set_global(String::from("output"), true);
+
+    let mut sub_config = clargs::ParsingConfig::new();
+    sub_config.add_flag(String::from("output"));
+    sub_config.inherit_globals(&config);
+
+    let args = ["sub", "-q", "--output"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &sub_config).unwrap();
+    assert!(result.has_flag("quiet"));
+    assert!(result.has_flag("output"));
+
+    let args = ["tool", "--output", "file"];
+    let parent = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let args = ["sub"];
+    let mut result = clargs::parse(args.iter().map(|x| x.to_string()), &sub_config).unwrap();
+    result.merge_globals(&parent, &sub_config);
+    assert_eq!(result.get_param("output"), None);
+    assert!(!result.has_flag("output"));
+}
+
+#[test]
+#[should_panic]
+fn global_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("quiet"));
+    config.add_alias(String::from("q"), String::from("quiet"));
+    config.set_global(String::from("q"), true);
+}
+
+#[test]
+fn global_test5() {
+    let config = clargs::ParsingConfig::builder()
+        .param("token").required().global()
+        .param("user").required()
+        .subcommand("remote")
+        .build()
+        .unwrap();
+    let remote_config = clargs::ParsingConfig::builder()
+        .inherit_globals(&config)
+        .subcommand("add")
+        .build()
+        .unwrap();
+    let add_config = clargs::ParsingConfig::builder()
+        .inherit_globals(&remote_config)
+        .build()
+        .unwrap();
+
+    let args = ["tool", "--user", "alice", "remote", "add", "--token", "abc"];
+    let result = clargs::parse_with_subcommands(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let remote_result = result.parse_subcommand(&remote_config).unwrap();
+    let add_result = remote_result.parse_subcommand(&add_config).unwrap();
+    assert_eq!(add_result.get_param("token"), Some("abc"));
+
+    let args = ["tool", "--user", "alice", "remote", "add"];
+    let result = clargs::parse_with_subcommands(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let remote_result = result.parse_subcommand(&remote_config).unwrap();
+    let add_result = remote_result.parse_subcommand(&add_config).err().unwrap();
+    assert_eq!(add_result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+
+    let args = ["tool", "--token", "abc", "remote"];
+    let result = clargs::parse_with_subcommands(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("user")]));
+
+    let args = ["tool", "--user", "alice"];
+    let result = clargs::parse_with_subcommands(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+}
+
+#[test]
+fn global_test6() {
+    let config = clargs::ParsingConfig::builder()
+        .param("token").required().global()
+        .subcommand("build")
+        .build()
+        .unwrap();
+    let build_config = clargs::ParsingConfig::builder()
+        .param("token")
+        .build()
+        .unwrap();
+
+    let args = ["tool", "build", "--token", "abc"];
+    let result = clargs::parse_with_subcommands(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let build_result = result.parse_subcommand(&clargs::ParsingConfig::new()).err().unwrap();
+    assert_eq!(build_result, clargs::ParsingError::UnrecognizedOption(String::from("token")));
+
+    let args = ["tool", "build"];
+    let result = clargs::parse_with_subcommands(args.iter().map(|x| x.to_string()), &config).unwrap();
+    let build_result = result.parse_subcommand(&build_config).err().unwrap();
+    assert_eq!(build_result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+    let build_result = result.parse_subcommand(&clargs::ParsingConfig::new()).err().unwrap();
+    assert_eq!(build_result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+}
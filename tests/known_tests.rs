@@ -0,0 +1,58 @@
+extern crate clargs;
+
+#[test]
+fn known_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .param("config")
+        .param("log-level")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--plugin-opt", "--config", "app.toml", "input", "--log-level=debug", "-x", "--", "--config"];
+    let (result, remainder) = clargs::parse_known(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.get_param("config"), Some("app.toml"));
+    assert_eq!(result.get_param("log-level"), Some("debug"));
+    assert_eq!(result.unnamed_params(), &Vec::<String>::new());
+    assert_eq!(result.unknown_args(), &[] as &[String]);
+    assert_eq!(remainder, vec![
+        String::from("--plugin-opt"),
+        String::from("input"),
+        String::from("-x"),
+        String::from("--"),
+        String::from("--config"),
+    ]);
+}
+
+#[test]
+fn known_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_param(String::from("config"), false);
+    config.add_passthrough_param(String::from("threads"));
+
+    let args = ["clargs", "--threads", "4", "file", "--config=app.toml"];
+    let (result, remainder) = clargs::parse_known(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("config"), Some("app.toml"));
+    assert_eq!(remainder, vec![String::from("--threads"), String::from("4"), String::from("file")]);
+
+    let mut plugin_config = clargs::ParsingConfig::new();
+    plugin_config.add_param(String::from("threads"), false);
+    let plugin_args = std::iter::once(String::from("clargs")).chain(remainder);
+    let result = clargs::parse(plugin_args, &plugin_config).unwrap();
+    assert_eq!(result.get_param_as::<u32>("threads"), Some(Ok(4)));
+    assert_eq!(result.unnamed_params(), &vec![String::from("file")]);
+}
+
+#[test]
+fn known_test3() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_param(String::from("config"), true);
+
+    let args = ["clargs", "--other"];
+    let result = clargs::parse_known(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("config")]));
+
+    let args = ["clargs", "--config"];
+    let result = clargs::parse_known(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingArgument(String::from("config")));
+}
@@ -0,0 +1,115 @@
+extern crate clargs;
+
+#[test]
+fn lazy_test1() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("null"));
+    config.add_param(String::from("max-args"), false);
+
+    let args = ["xargs", "--null", "--max-args", "2", "file1", "file2"];
+    let mut result = clargs::parse_lazy(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.parsed_args().has_flag("null"));
+    assert_eq!(result.parsed_args().get_param("max-args"), Some("2"));
+    assert_eq!(result.next(), Some(Ok(String::from("file1"))));
+    assert_eq!(result.next(), Some(Ok(String::from("file2"))));
+    assert_eq!(result.next(), None);
+    assert!(result.parsed_args().unnamed_params().is_empty());
+}
+
+#[test]
+fn lazy_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("v"));
+
+    let args = ["xargs", "-x", "file1"];
+    let result = clargs::parse_lazy(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("x")));
+}
+
+#[test]
+fn lazy_test3() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("v"));
+    config.add_param(String::from("p"), true);
+
+    let args = ["xargs", "file1", "-v", "file2"];
+    let result = clargs::parse_lazy(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("p")]));
+
+    let args = ["xargs", "file1", "-v", "file2", "-p", "x"];
+    let mut result = clargs::parse_lazy(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.next(), Some(Ok(String::from("file1"))));
+    assert!(!result.parsed_args().has_flag("v"));
+    assert_eq!(result.next(), Some(Ok(String::from("file2"))));
+    assert!(result.parsed_args().has_flag("v"));
+    assert_eq!(result.next(), None);
+    assert_eq!(result.parsed_args().get_param("p"), Some("x"));
+}
+
+#[test]
+fn lazy_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_store_double_hyphen_marker(true);
+    config.add_flag(String::from("v"));
+
+    let args = ["xargs", "-v", "--", "-v"];
+    let result: Result<Vec<String>, clargs::ParsingError> = clargs::parse_lazy(args.iter().map(|x| x.to_string()), &config).unwrap().collect();
+    assert_eq!(result, Ok(vec![String::from("--"), String::from("-v")]));
+}
+
+#[test]
+fn lazy_test5() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("null"));
+
+    let args = std::iter::once(String::from("xargs"))
+        .chain(std::iter::once(String::from("--null")))
+        .chain((0..100000).map(|x| format!("file{}", x)));
+    let mut result = clargs::parse_lazy(args, &config).unwrap();
+
+    assert!(result.parsed_args().has_flag("null"));
+    assert_eq!(result.by_ref().filter(|x| x.is_ok()).count(), 100000);
+}
+
+#[test]
+fn lazy_test6() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("null"));
+    config.add_subcommand(String::from("run"));
+
+    let args = std::iter::once(String::from("xargs"))
+        .chain(vec![String::from("--null"), String::from("file"), String::from("run"), String::from("--null")])
+        .chain((0..100000).map(|x| format!("file{}", x)));
+    let mut result = clargs::parse_lazy(args, &config).unwrap();
+
+    assert_eq!(result.next(), Some(Ok(String::from("file"))));
+    assert_eq!(result.next(), None);
+    assert_eq!(result.parsed_args().get_subcommand(), Some("run"));
+    assert_eq!(result.parsed_args().subcommand_args(), &vec![String::from("run")]);
+    let mut rest = result.into_subcommand_args().unwrap();
+    assert_eq!(rest.next(), Some(String::from("--null")));
+    assert_eq!(rest.count(), 100000);
+
+    let args = ["xargs", "file"];
+    let mut result = clargs::parse_lazy(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.next(), Some(Ok(String::from("file"))));
+    assert_eq!(result.next(), None);
+    assert!(result.into_subcommand_args().is_none());
+}
+
+#[test]
+fn lazy_test7() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("null"));
+
+    let args = ["xargs", "a", "b", "--bogus"];
+    let result = clargs::parse_lazy(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("bogus")));
+
+    let args = std::iter::once(String::from("xargs"))
+        .chain((0..100000).map(|x| format!("file{}", x)))
+        .chain(std::iter::once(String::from("--bogus")));
+    let result = clargs::parse_lazy(args, &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("bogus")));
+}
@@ -0,0 +1,96 @@
+extern crate clargs;
+
+#[test]
+fn lint_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose").short('v')
+        .param("out").short('o')
+        .subcommand("build")
+        .build()
+        .unwrap();
+
+    assert_eq!(config.lint(), vec![]);
+}
+
+#[test]
+fn lint_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("color"));
+    config.add_flag(String::from("colors"));
+    config.add_param(String::from("colorscheme"), false);
+    config.add_flag(String::from("v"));
+    config.add_flag(String::from("verbose"));
+
+    assert_eq!(config.lint(), vec![
+        clargs::ConfigLint::PrefixOption(String::from("color"), String::from("colors")),
+        clargs::ConfigLint::PrefixOption(String::from("color"), String::from("colorscheme")),
+        clargs::ConfigLint::PrefixOption(String::from("colors"), String::from("colorscheme")),
+    ]);
+
+    config.set_option_completion(false);
+    assert_eq!(config.lint(), vec![]);
+}
+
+#[test]
+fn lint_test3() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.add_flag(String::from("v"));
+    config.add_alias(String::from("verbose"), String::from("v"));
+    config.add_param(String::from("o"), false);
+    config.add_alias(String::from("out"), String::from("o"));
+
+    assert_eq!(config.lint(), vec![
+        clargs::ConfigLint::UnreachableOption(String::from("out")),
+        clargs::ConfigLint::UnreachableOption(String::from("verbose")),
+    ]);
+
+    config.set_double_hyphen_assignment_syntax(true);
+    assert_eq!(config.lint(), vec![
+        clargs::ConfigLint::UnreachableOption(String::from("verbose")),
+        clargs::ConfigLint::UnreachableAlias(String::from("out"), clargs::Syntax::SingleHyphen),
+    ]);
+
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::LongOrClustered);
+    assert_eq!(config.lint(), vec![]);
+}
+
+#[test]
+fn lint_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_subcommand_completion(true);
+    config.set_subcommand_required(true);
+
+    assert_eq!(config.lint(), vec![
+        clargs::ConfigLint::SubcommandCompletionWithoutIndex,
+        clargs::ConfigLint::SubcommandRequiredWithoutSubcommands,
+    ]);
+    assert_eq!(config.lint()[1].to_string(), "a subcommand is required but there are no subcommands");
+
+    config.set_subcommand_index(true);
+    config.add_subcommand(String::from("build"));
+    assert_eq!(config.lint(), vec![]);
+}
+
+#[test]
+fn lint_test5() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_alias(String::from("verb"), String::from("verbose"));
+    config.add_flag(String::from("version"));
+    config.add_alias(String::from("v"), String::from("version"));
+
+    let lints = config.lint();
+    assert_eq!(lints, vec![clargs::ConfigLint::UnreachableAlias(String::from("verb"), clargs::Syntax::SingleHyphen)]);
+    assert_eq!(lints[0].to_string(), "alias 'verb' cannot be specified with single hyphen syntax");
+
+    config.add_alias(String::from("vers"), String::from("version"));
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::LongOrClustered);
+    assert_eq!(config.lint(), vec![]);
+
+    config.add_flag(String::from("verbose-all"));
+    assert_eq!(config.lint(), vec![
+        clargs::ConfigLint::PrefixOption(String::from("verb"), String::from("verbose-all")),
+        clargs::ConfigLint::PrefixOption(String::from("verbose"), String::from("verbose-all")),
+    ]);
+}
@@ -0,0 +1,64 @@
+extern crate clargs;
+
+use clargs::Syntax;
+
+#[test]
+fn occurrence_test1() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_alias(String::from("v"), String::from("verbose"));
+    config.add_param(String::from("output"), false);
+
+    let args = ["clargs", "-v", "file", "--verb", "--out=result"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    let flags = result.flag_occurrences("verbose");
+    assert_eq!(flags.len(), 2);
+    assert_eq!((flags[0].index(), flags[0].typed(), flags[0].syntax()), (1, "v", Some(Syntax::SingleHyphen)));
+    assert_eq!((flags[1].index(), flags[1].typed(), flags[1].syntax()), (3, "verb", Some(Syntax::DoubleHyphen)));
+
+    let params = result.param_occurrences("output");
+    assert_eq!(params.len(), 1);
+    assert_eq!((params[0].index(), params[0].typed(), params[0].syntax()), (4, "out", Some(Syntax::DoubleHyphenAssignment)));
+
+    let unnameds = result.unnamed_occurrences();
+    assert_eq!(unnameds.len(), 1);
+    assert_eq!((unnameds[0].index(), unnameds[0].typed(), unnameds[0].syntax()), (2, "", None));
+}
+
+#[test]
+fn occurrence_test2() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_assignment_syntax(true);
+    config.set_plus_syntax(true);
+    config.set_store_double_hyphen_marker(true);
+    config.set_double_hyphen_marker(true);
+    config.add_flag(String::from("e"));
+    config.add_param(String::from("level"), false);
+
+    let args = ["clargs", "level=3", "+e", "--", "+e"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    let params = result.param_occurrences("level");
+    assert_eq!((params[0].index(), params[0].typed(), params[0].syntax()), (1, "level", Some(Syntax::Assignment)));
+
+    let flags = result.flag_occurrences("e");
+    assert_eq!(flags.len(), 1);
+    assert_eq!((flags[0].index(), flags[0].syntax()), (2, Some(Syntax::Plus)));
+
+    let unnameds = result.unnamed_occurrences();
+    assert_eq!(unnameds.iter().map(|x| x.index()).collect::<Vec<usize>>(), vec![3, 4]);
+    assert_eq!(result.unnamed_params(), &vec![String::from("--"), String::from("+e")]);
+}
+
+#[test]
+fn occurrence_test3() {
+    let config = clargs::ParsingConfig::new();
+
+    let args = ["clargs"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.flag_occurrences("verbose").is_empty());
+    assert!(result.param_occurrences("output").is_empty());
+    assert!(result.unnamed_occurrences().is_empty());
+}
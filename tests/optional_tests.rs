@@ -0,0 +1,135 @@
+extern crate clargs;
+
+#[test]
+fn optional_test1() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_double_hyphen_syntax(true);
+    config.set_double_hyphen_assignment_syntax(true);
+    config.add_optional_param(String::from("color"), String::from("always"));
+    config.add_optional_param(String::from("width"), String::from("80"));
+
+    let args = ["clargs", "--color", "never", "--width=120"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.get_param("color"), Some("always"));
+    assert_eq!(result.get_param_as::<u32>("width"), Some(Ok(120)));
+    assert_eq!(result.unnamed_params(), &vec![String::from("never")]);
+}
+
+#[test]
+fn optional_test2() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.add_flag(String::from("v"));
+    config.add_optional_param(String::from("c"), String::from("1"));
+    config.add_optional_param(String::from("d"), String::from("1"));
+
+    let args = ["clargs", "-cvd2", "value"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("v"));
+    assert_eq!(result.get_param("c"), Some("1"));
+    assert_eq!(result.get_param("d"), Some("2"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("value")]);
+}
+
+#[test]
+fn optional_test3() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_parameter_stacking(true);
+    config.add_optional_param(String::from("c"), String::from("1"));
+    config.add_param(String::from("p"), false);
+
+    let args = ["clargs", "-pc2", "value"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.get_param("c"), Some("2"));
+    assert_eq!(result.get_param("p"), Some("value"));
+}
+
+#[test]
+fn optional_test4() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.add_optional_param(String::from("c"), String::from("1"));
+    config.add_flag(String::from("v"));
+
+    let args = ["clargs", "-cv2"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AssignmentToFlag(String::from("v")));
+}
+
+#[test]
+fn optional_test5() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_double_hyphen_syntax(true);
+    config.set_double_hyphen_assignment_syntax(true);
+    config.set_single_hyphen_syntax(true);
+    config.add_optional_param(String::from("color"), String::from("always"));
+    config.add_alias(String::from("c"), String::from("color"));
+
+    let args = ["clargs", "--color=never", "-c"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::ParameterDuplicationAlias(String::from("color"), String::from("c")));
+}
+
+#[test]
+fn optional_test6() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_slash_syntax(true);
+    config.add_optional_param(String::from("color"), String::from("always"));
+
+    let args = ["clargs", "/color", "never"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.get_param("color"), Some("always"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("never")]);
+}
+
+#[test]
+fn optional_test7() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::Long);
+    config.add_optional_param(String::from("c"), String::from("1"));
+    config.add_optional_param(String::from("color"), String::from("always"));
+    config.add_flag(String::from("verbose"));
+
+    let args = ["clargs", "-c2", "-color"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("c"), Some("2"));
+    assert!(!result.used_implicit("c"));
+    assert_eq!(result.get_param("color"), Some("always"));
+    assert!(result.used_implicit("color"));
+
+    let events = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(!events[0].is_implicit());
+    assert!(events[1].is_implicit());
+
+    let args = ["clargs", "-verbose2"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("verbose2")));
+}
+
+#[test]
+fn optional_test8() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_parameter_duplication(true);
+    config.add_optional_param(String::from("color"), String::from("always"));
+    config.add_alias(String::from("c"), String::from("color"));
+    config.set_choices(String::from("color"), vec![String::from("auto"), String::from("never")]);
+
+    let args = ["clargs", "-c"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("color"), Some("always"));
+    assert!(result.used_implicit("color"));
+
+    let args = ["clargs", "--color", "--color=never"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("color"), Some("never"));
+    assert!(!result.used_implicit("color"));
+
+    let args = ["clargs", "--color=always"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::InvalidChoice(String::from("color"), String::from("always"), vec![String::from("auto"), String::from("never")]));
+}
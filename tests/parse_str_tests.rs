@@ -0,0 +1,68 @@
+extern crate clargs;
+
+#[test]
+fn parse_str_test1() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_param(String::from("message"), false);
+
+    let result = clargs::parse_str("tool --verbose --message 'hello  world' \"a \\\"b\\\" \\c\" d\\ e", &config).unwrap();
+
+    assert_eq!(result.name(), "tool");
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("message"), Some("hello  world"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("a \"b\" \\c"), String::from("d e")]);
+}
+
+#[test]
+fn parse_str_test2() {
+    let config = clargs::ParsingConfig::new();
+
+    let result = clargs::parse_str("tool a'b'\"c\" '' x#y # comment 'ignored\nz \\\nw", &config).unwrap();
+    assert_eq!(result.unnamed_params(), &vec![
+        String::from("abc"),
+        String::new(),
+        String::from("x#y"),
+        String::from("z"),
+        String::from("w"),
+    ]);
+
+    let result = clargs::parse_str("", &config).unwrap();
+    assert_eq!(result.name(), "");
+}
+
+#[test]
+fn parse_str_test3() {
+    let config = clargs::ParsingConfig::new();
+
+    let result = clargs::parse_str("tool 'abc", &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnterminatedQuote(1, 6));
+    assert_eq!(result.to_string(), "unterminated quote at line 1, column 6");
+
+    let result = clargs::parse_str("tool \"ab\\\"", &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnterminatedQuote(1, 6));
+
+    let result = clargs::parse_str("tool abc\\", &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::TrailingBackslash(1, 9));
+}
+
+#[test]
+fn parse_str_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_param(String::from("out"), false);
+
+    let result = clargs::parse_str("tool --out", &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingArgument(String::from("out")));
+}
+
+#[test]
+fn parse_str_test5() {
+    let config = clargs::ParsingConfig::new();
+
+    let result = clargs::parse_str("tool \\\n  abc 'def\n", &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnterminatedQuote(2, 7));
+    assert_eq!(result.to_string(), "unterminated quote at line 2, column 7");
+
+    let result = clargs::parse_str("tool # comment\n\"abc\" \"\\", &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::TrailingBackslash(2, 8));
+}
@@ -0,0 +1,76 @@
+extern crate clargs;
+
+#[test]
+fn passthrough_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .unknown_passthrough(true)
+        .flag("verbose")
+        .param("out")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--jobs=4", "--verbose", "--keep-going", "file", "--out", "dir", "-xz"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("out"), Some("dir"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("file")]);
+    assert_eq!(result.unknown_args(), &[String::from("--jobs=4"), String::from("--keep-going"), String::from("-xz")]);
+}
+
+#[test]
+fn passthrough_test2() {
+    let config = clargs::ParsingConfig::builder()
+        .unknown_passthrough(true)
+        .passthrough_param("target")
+        .passthrough_param("I")
+        .flag("verbose")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--target", "x86", "-I", "include", "-Isrc", "--other", "file"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.unknown_args(), &[
+        String::from("--target"), String::from("x86"),
+        String::from("-I"), String::from("include"),
+        String::from("-Isrc"),
+        String::from("--other"),
+    ]);
+    assert_eq!(result.unnamed_params(), &vec![String::from("file")]);
+}
+
+#[test]
+fn passthrough_test3() {
+    let config = clargs::ParsingConfig::builder()
+        .unknown_passthrough(true)
+        .flag("v")
+        .flag("q")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "-vx", "-q", "--", "--unknown"];
+    let events = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(events.iter().map(|x| x.kind().clone()).collect::<Vec<_>>(), vec![
+        clargs::EventKind::Unknown(String::from("-vx")),
+        clargs::EventKind::Flag(String::from("q")),
+        clargs::EventKind::Marker,
+        clargs::EventKind::Unnamed(String::from("--unknown")),
+    ]);
+    assert_eq!(events[0].index(), 1);
+}
+
+#[test]
+fn passthrough_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+
+    let args = ["clargs", "--unknown"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("unknown")));
+
+    config.set_unknown_passthrough(true);
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.unknown_args(), &[String::from("--unknown")]);
+}
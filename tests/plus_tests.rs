@@ -0,0 +1,71 @@
+extern crate clargs;
+
+#[test]
+fn plus_test1() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_plus_syntax(true);
+    config.add_flag(String::from("e"));
+    config.add_flag(String::from("x"));
+    config.add_flag(String::from("u"));
+
+    let args = ["clargs", "+ex"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag_off("e"));
+    assert!(result.has_flag_off("x"));
+    assert!(!result.has_flag_off("u"));
+    assert!(!result.has_flag("e"));
+}
+
+#[test]
+fn plus_test2() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_plus_syntax(true);
+    config.add_flag(String::from("e"));
+    config.add_flag(String::from("x"));
+
+    let args = ["clargs", "-ex", "+e", "-e", "+x"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("e"));
+    assert!(!result.has_flag_off("e"));
+    assert!(!result.has_flag("x"));
+    assert!(result.has_flag_off("x"));
+}
+
+#[test]
+fn plus_test3() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_plus_syntax(true);
+    config.add_flag(String::from("e"));
+
+    let args = ["clargs", "+eq"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("q")));
+}
+
+#[test]
+fn plus_test4() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_plus_syntax(true);
+    config.add_param(String::from("param"), false);
+    config.add_alias(String::from("p"), String::from("param"));
+
+    let args = ["clargs", "+p"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::ToggledParameterAlias(String::from("param"), String::from("p")));
+}
+
+#[test]
+fn plus_test5() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_plus_syntax(true);
+    config.add_flag(String::from("e"));
+
+    let args = ["clargs", "+", "+5", "+e1"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(!result.has_flag_off("e"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("+"), String::from("+5"), String::from("+e1")]);
+}
@@ -0,0 +1,117 @@
+extern crate clargs;
+
+struct StubPrompter {
+    answers: Vec<&'static str>,
+    prompts: Vec<(String, bool)>,
+    rejections: Vec<String>,
+}
+
+impl clargs::Prompter for StubPrompter {
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn prompt(&mut self, name: &str, secret: bool) -> Option<String> {
+        self.prompts.push((name.to_string(), secret));
+        if self.answers.is_empty() {
+            None
+        } else {
+            Some(self.answers.remove(0).to_string())
+        }
+    }
+
+    fn reject(&mut self, _name: &str, error: &clargs::ParsingError) {
+        self.rejections.push(error.to_string());
+    }
+}
+
+#[test]
+fn prompt_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .param("token").required().secret()
+        .param("user").required()
+        .flag("verbose")
+        .build()
+        .unwrap();
+
+    let mut prompter = StubPrompter { answers: vec!["secret-token", "alice"], prompts: Vec::new(), rejections: Vec::new() };
+    let args = ["clargs", "--verbose"];
+    let result = clargs::parse_with_prompter(args.iter().map(|x| x.to_string()), &config, &mut prompter).unwrap();
+
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("token"), Some("secret-token"));
+    assert_eq!(result.get_param("user"), Some("alice"));
+    assert_eq!(prompter.prompts, vec![(String::from("token"), true), (String::from("user"), false)]);
+}
+
+#[test]
+fn prompt_test2() {
+    let config = clargs::ParsingConfig::builder()
+        .param("color").required().choices(vec!["always", "never"])
+        .build()
+        .unwrap();
+
+    let mut prompter = StubPrompter { answers: vec!["sometimes", "never"], prompts: Vec::new(), rejections: Vec::new() };
+    let args = ["clargs"];
+    let result = clargs::parse_with_prompter(args.iter().map(|x| x.to_string()), &config, &mut prompter).unwrap();
+
+    assert_eq!(result.get_param("color"), Some("never"));
+    assert_eq!(prompter.rejections, vec![String::from("invalid value 'sometimes' for parameter 'color'; possibilities: 'always' 'never'")]);
+}
+
+#[test]
+fn prompt_test3() {
+    let config = clargs::ParsingConfig::builder()
+        .param("token").required()
+        .param("user").required()
+        .build()
+        .unwrap();
+
+    let mut prompter = StubPrompter { answers: vec!["secret-token"], prompts: Vec::new(), rejections: Vec::new() };
+    let args = ["clargs"];
+    let result = clargs::parse_with_prompter(args.iter().map(|x| x.to_string()), &config, &mut prompter).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("user")]));
+}
+
+#[test]
+fn prompt_test4() {
+    let config = clargs::ParsingConfig::builder()
+        .param("token").required()
+        .build()
+        .unwrap();
+
+    let mut output = Vec::new();
+    let args = ["clargs"];
+    {
+        let mut prompter = clargs::IoPrompter::new("abc\r\n".as_bytes(), &mut output, true);
+        let result = clargs::parse_with_prompter(args.iter().map(|x| x.to_string()), &config, &mut prompter).unwrap();
+        assert_eq!(result.get_param("token"), Some("abc"));
+    }
+    assert_eq!(output, b"token: ");
+
+    let mut prompter = clargs::IoPrompter::new("abc\n".as_bytes(), Vec::new(), false);
+    let result = clargs::parse_with_prompter(args.iter().map(|x| x.to_string()), &config, &mut prompter).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+}
+
+#[test]
+fn prompt_test5() {
+    use std::io::IsTerminal;
+
+    let config = clargs::ParsingConfig::builder()
+        .param("token").required().secret()
+        .build()
+        .unwrap();
+
+    let mut output = Vec::new();
+    let args = ["clargs"];
+    {
+        let mut prompter = clargs::IoPrompter::new("abc\n".as_bytes(), &mut output, true);
+        let result = clargs::parse_with_prompter(args.iter().map(|x| x.to_string()), &config, &mut prompter).err().unwrap();
+        assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("token")]));
+    }
+    assert!(output.is_empty());
+
+    let prompter = clargs::IoPrompter::stdio();
+    assert_eq!(clargs::Prompter::is_interactive(&prompter), std::io::stdin().is_terminal());
+}
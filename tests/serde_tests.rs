@@ -0,0 +1,95 @@
+#![cfg(feature = "serde")]
+
+extern crate clargs;
+extern crate serde_json;
+
+#[test]
+fn serde_test1() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_assignment_syntax(true);
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::LongOrClustered);
+    config.set_subcommand_index(true);
+    config.add_flag(String::from("verbose"));
+    config.add_alias(String::from("v"), String::from("verbose"));
+    config.add_param(String::from("output"), true);
+    config.add_optional_param(String::from("color"), String::from("always"));
+    config.add_subcommand(String::from("build"));
+
+    let json = serde_json::to_string(&config).unwrap();
+    let copy: clargs::ParsingConfig = serde_json::from_str(&json).unwrap();
+
+    let args = ["clargs", "-v", "--out", "file", "--color", "level=3", "build", "-x"];
+    let expected = clargs::parse_events(args.iter().map(|x| x.to_string()), &config);
+    let result = clargs::parse_events(args.iter().map(|x| x.to_string()), &copy);
+    assert_eq!(result, expected);
+
+    let args = ["clargs", "-x"];
+    let expected = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &copy).err().unwrap();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn serde_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_param(String::from("output"), false);
+
+    let args = ["clargs", "--verbose", "--output", "file", "unnamed"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    let json = serde_json::to_string(&result).unwrap();
+    let copy: clargs::ParsedArgs = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(copy.name(), "clargs");
+    assert!(copy.has_flag("verbose"));
+    assert_eq!(copy.get_param("output"), Some("file"));
+    assert_eq!(copy.unnamed_params(), &vec![String::from("unnamed")]);
+    assert_eq!(copy.param_occurrences("output"), result.param_occurrences("output"));
+}
+
+#[test]
+fn serde_test3() {
+    let error = clargs::ParsingError::AmbiguousOption(String::from("ver"), vec![String::from("verbose"), String::from("version")]);
+
+    let json = serde_json::to_string(&error).unwrap();
+    let copy: clargs::ParsingError = serde_json::from_str(&json).unwrap();
+    assert_eq!(copy, error);
+}
+
+#[test]
+fn serde_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_alias(String::from("v"), String::from("verbose"));
+    config.add_alias(String::from("w"), String::from("v"));
+    config.add_subcommand(String::from("build"));
+    config.add_subcommand_alias(String::from("b"), String::from("build"));
+
+    let json = serde_json::to_string(&config).unwrap();
+    let copy: clargs::ParsingConfig = serde_json::from_str(&json).unwrap();
+
+    let args = ["clargs", "-w", "b"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &copy).unwrap();
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_subcommand(), Some("build"));
+
+    let json = json.replace("{\"Alias\":\"verbose\"}", "{\"Alias\":\"missing\"}");
+    let error = serde_json::from_str::<clargs::ParsingConfig>(&json).err().unwrap();
+    assert_eq!(error.to_string(), "alias 'v' points to 'missing', which does not exist");
+}
+
+#[test]
+fn serde_test5() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_param(String::from("out"), false);
+    config.set_choices(String::from("out"), vec![String::from("file")]);
+
+    let json = serde_json::to_string(&config).unwrap();
+    let error = serde_json::from_str::<clargs::ParsingConfig>(&json.replace("\"out\"", "\"out put\"")).err().unwrap();
+    assert_eq!(error.to_string(), "'out put' is not a valid name");
+
+    let json = json.replace("\"choices\":{\"out\"", "\"choices\":{\"other\"");
+    let error = serde_json::from_str::<clargs::ParsingConfig>(&json).err().unwrap();
+    assert_eq!(error.to_string(), "invalid setting for 'other'");
+}
@@ -0,0 +1,96 @@
+extern crate clargs;
+
+#[test]
+fn shl_test1() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::Long);
+    config.add_flag(String::from("Xmx"));
+    config.add_param(String::from("name"), false);
+    config.add_param(String::from("maxdepth"), false);
+
+    let args = ["clargs", "-name", "foo", "-Xmx", "-maxdepth", "2"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("Xmx"));
+    assert_eq!(result.get_param("name"), Some("foo"));
+    assert_eq!(result.get_param("maxdepth"), Some("2"));
+}
+
+#[test]
+fn shl_test2() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::Long);
+    config.set_option_completion(true);
+    config.add_param(String::from("maxdepth"), false);
+    config.add_param(String::from("mindepth"), false);
+
+    let args = ["clargs", "-max", "2", "-mi", "1"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.get_param("maxdepth"), Some("2"));
+    assert_eq!(result.get_param("mindepth"), Some("1"));
+}
+
+#[test]
+fn shl_test3() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::Long);
+    config.add_flag(String::from("a"));
+    config.add_flag(String::from("b"));
+
+    let args = ["clargs", "-ab"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("ab")));
+}
+
+#[test]
+fn shl_test4() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::LongOrClustered);
+    config.add_flag(String::from("a"));
+    config.add_flag(String::from("b"));
+    config.add_flag(String::from("ab"));
+    config.add_flag(String::from("c"));
+    config.add_param(String::from("p"), false);
+
+    let args = ["clargs", "-ab", "-bc", "-p5"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("ab"));
+    assert!(!result.has_flag("a"));
+    assert!(result.has_flag("b"));
+    assert!(result.has_flag("c"));
+    assert_eq!(result.get_param("p"), Some("5"));
+}
+
+#[test]
+fn shl_test5() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::LongOrClustered);
+    config.set_option_completion(true);
+    config.add_flag(String::from("v"));
+    config.add_flag(String::from("verbose"));
+    config.add_flag(String::from("version"));
+
+    let args = ["clargs", "-ver"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AmbiguousOption(String::from("ver"), vec![String::from("verbose"), String::from("version")]));
+}
+
+#[test]
+fn shl_test6() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_single_hyphen_syntax(true);
+    config.set_single_hyphen_mode(clargs::SingleHyphenMode::LongOrClustered);
+    config.add_flag(String::from("a"));
+    config.add_flag(String::from("b"));
+
+    let args = ["clargs", "-abx"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("x")));
+}
@@ -0,0 +1,85 @@
+extern crate clargs;
+
+#[test]
+fn slash_test1() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_slash_syntax(true);
+    config.add_flag(String::from("flag"));
+    config.add_param(String::from("param"), false);
+
+    let args = ["clargs", "/flag", "/param:value"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("flag"));
+    assert_eq!(result.get_param("param"), Some("value"));
+}
+
+#[test]
+fn slash_test2() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_slash_syntax(true);
+    config.set_slash_separator('=');
+    config.add_param(String::from("param"), false);
+
+    let args = ["clargs", "/param=C:\\dir", "/param:value"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.get_param("param"), Some("C:\\dir"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("/param:value")]);
+}
+
+#[test]
+fn slash_test3() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_slash_syntax(true);
+    config.set_option_completion(true);
+    config.add_flag(String::from("usr"));
+    config.add_param(String::from("param"), false);
+
+    let args = ["clargs", "/usr/bin", "/p", "/param", "/etc", "/"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(!result.has_flag("usr"));
+    assert_eq!(result.get_param("param"), Some("/etc"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("/usr/bin"), String::from("/p"), String::from("/")]);
+}
+
+#[test]
+fn slash_test4() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_slash_syntax(true);
+    config.add_flag(String::from("flag"));
+    config.add_alias(String::from("f"), String::from("flag"));
+
+    let args = ["clargs", "/f:value"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AssignmentToFlagAlias(String::from("flag"), String::from("f")));
+}
+
+#[test]
+fn slash_test5() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_slash_syntax(true);
+    config.set_option_completion(true);
+    config.set_case_insensitive(true);
+    config.add_flag(String::from("bin-a"));
+    config.add_flag(String::from("bin-b"));
+    config.add_param(String::from("tmpdir"), false);
+
+    let args = ["clargs", "/tmp", "x", "/bin", "/BIN-A"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("tmpdir"), None);
+    assert!(result.has_flag("bin-a"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("/tmp"), String::from("x"), String::from("/bin")]);
+}
+
+#[test]
+fn slash_test6() {
+    let mut config = clargs::ParsingConfig::new_all_disabled();
+    config.set_slash_syntax(true);
+    config.add_param(String::from("param"), false);
+
+    let args = ["clargs", "/param"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingArgument(String::from("param")));
+}
@@ -0,0 +1,86 @@
+extern crate clargs;
+
+#[test]
+fn subcommand_alias_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose")
+        .subcommand("remove").alias("rm")
+        .subcommand("checkout").alias("co")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--verbose", "rm", "file"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_subcommand(), Some("remove"));
+    assert_eq!(result.subcommand_alias(), Some("rm"));
+    assert_eq!(result.subcommand_args(), &vec![String::from("remove"), String::from("file")]);
+
+    let args = ["clargs", "checkout", "main"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_subcommand(), Some("checkout"));
+    assert_eq!(result.subcommand_alias(), None);
+
+    let args = ["clargs", "co"];
+    let events = clargs::parse_events(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(events[0].kind(), &clargs::EventKind::Subcommand(String::from("checkout"), Vec::new()));
+    assert_eq!(events[0].alias(), Some("co"));
+}
+
+#[test]
+fn subcommand_alias_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_subcommand_index(true);
+    config.set_subcommand_completion(true);
+    config.set_subcommand_required(true);
+    config.add_subcommand(String::from("remove"));
+    config.add_subcommand(String::from("rebase"));
+    config.add_subcommand_alias(String::from("rm"), String::from("remove"));
+    config.add_subcommand_alias(String::from("delete"), String::from("rm"));
+
+    let args = ["clargs", "rem"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_subcommand(), Some("remove"));
+    assert_eq!(result.subcommand_alias(), None);
+
+    let args = ["clargs", "del"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_subcommand(), Some("remove"));
+    assert_eq!(result.subcommand_alias(), Some("delete"));
+
+    let args = ["clargs", "r"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::AmbiguousSubcommand(String::from("r"), vec![String::from("rebase"), String::from("remove")]));
+
+    let args = ["clargs", "x"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedSubcommand(String::from("x")));
+}
+
+#[test]
+fn subcommand_alias_test3() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_subcommand_index(true);
+    config.set_subcommand_index_value(1);
+    config.set_case_insensitive(true);
+    config.add_subcommand(String::from("checkout"));
+    config.add_subcommand_alias(String::from("co"), String::from("checkout"));
+
+    let args = ["clargs", "co", "CO", "x"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.unnamed_params(), &vec![String::from("co")]);
+    assert_eq!(result.get_subcommand(), Some("checkout"));
+    assert_eq!(result.subcommand_alias(), Some("co"));
+}
+
+#[test]
+fn subcommand_alias_test4() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_subcommand(String::from("remove"));
+
+    assert_eq!(config.try_add_subcommand_alias(String::from("verbose"), String::from("remove")), Err(clargs::ConfigError::NameTaken(String::from("verbose"))));
+    assert_eq!(config.try_add_subcommand_alias(String::from("rm"), String::from("rm")), Err(clargs::ConfigError::AliasSelfReference(String::from("rm"))));
+    assert_eq!(config.try_add_subcommand_alias(String::from("rm"), String::from("verbose")), Err(clargs::ConfigError::AliasTargetMissing(String::from("rm"), String::from("verbose"))));
+    assert_eq!(config.try_add_subcommand_alias(String::from("rm"), String::from("remove")), Ok(()));
+    assert_eq!(config.try_add_flag(String::from("rm")), Err(clargs::ConfigError::NameTaken(String::from("rm"))));
+}
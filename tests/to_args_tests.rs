@@ -0,0 +1,220 @@
+extern crate clargs;
+
+fn round_trip(args: &[&str], config: &clargs::ParsingConfig) -> Vec<String> {
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), config).unwrap();
+    let canonical = result.to_args(config).unwrap();
+    let again = clargs::parse(canonical.clone().into_iter(), config).unwrap();
+
+    assert_eq!(again.name(), result.name());
+    assert_eq!(again.unnamed_params(), result.unnamed_params());
+    assert_eq!(again.subcommand_args(), result.subcommand_args());
+    assert_eq!(again.unknown_args(), result.unknown_args());
+    for name in ["verbose", "v", "q", "out", "o", "color", "level", ""].iter() {
+        assert_eq!(again.has_flag(name), result.has_flag(name));
+        assert_eq!(again.has_flag_off(name), result.has_flag_off(name));
+        assert_eq!(again.get_param(name), result.get_param(name));
+        assert_eq!(again.used_implicit(name), result.used_implicit(name));
+    }
+    canonical
+}
+
+#[test]
+fn to_args_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose").short('v')
+        .param("out").short('o')
+        .optional_param("color", "always")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "file1", "-vo", "dir", "--col", "-", "file2"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--color", "--out=dir", "--verbose", "file1", "-", "file2"]);
+}
+
+#[test]
+fn to_args_test2() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose")
+        .param("out")
+        .subcommand("build")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--out", "--", "--", "-x", "build"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--out=--", "--", "-x", "build"]);
+
+    let args = ["clargs", "--verbose", "file", "build", "--release"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--verbose", "file", "build", "--release"]);
+}
+
+#[test]
+fn to_args_test3() {
+    let config = clargs::ParsingConfig::builder_all_disabled()
+        .single_hyphen_syntax(true)
+        .plus_syntax(true)
+        .slash_syntax(true)
+        .flag("v")
+        .flag("q")
+        .param("level")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "-v", "+q", "/level:3"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "/level:3", "+q", "-v"]);
+}
+
+#[test]
+fn to_args_test4() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose")
+        .param("out")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--out", "x y", "--verbose"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    let mut command = std::process::Command::new("echo");
+    result.apply_to_command(&config, &mut command).unwrap();
+    let output = command.output().unwrap();
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "--out=x y --verbose\n");
+}
+
+#[test]
+fn to_args_test5() {
+    let config = clargs::ParsingConfig::builder()
+        .case_insensitive(true)
+        .subcommand("build").alias("b")
+        .build()
+        .unwrap();
+
+    for value in ["b", "BUILD"].iter() {
+        let args = ["clargs", "--", value];
+        let canonical = round_trip(&args, &config);
+        assert_eq!(canonical, vec!["clargs", "--", value]);
+    }
+
+    let config = clargs::ParsingConfig::builder()
+        .subcommand_index(true)
+        .subcommand_completion(true)
+        .subcommand("build")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--", "bu", "bu"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--", "bu", "bu"]);
+
+    let args = ["clargs", "file", "bu"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "file", "bu"]);
+}
+
+#[test]
+fn to_args_test6() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("")
+        .optional_param("level", "1")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "-", "--level", "file"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "-", "--level", "file"]);
+
+    let mut config = clargs::ParsingConfig::builder()
+        .param("")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--=abc"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--=abc"]);
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    config.set_double_hyphen_assignment_syntax(false);
+    assert_eq!(result.to_args(&config).err(), Some(clargs::ParsingError::UnrepresentableOption(String::new())));
+
+    let args = ["clargs", "-5"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "-5"]);
+}
+
+#[test]
+fn to_args_test7() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("verbose"));
+    config.add_flag(String::from("v"));
+
+    let args = ["clargs", "--verbose", "--", "-v"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    config.set_double_hyphen_syntax(false);
+    assert_eq!(result.to_args(&config).err(), Some(clargs::ParsingError::UnrepresentableOption(String::from("verbose"))));
+
+    let mut command = std::process::Command::new("echo");
+    let error = result.apply_to_command(&config, &mut command).err().unwrap();
+    assert_eq!(error.to_string(), "option 'verbose' cannot be specified with any enabled syntax");
+
+    config.set_double_hyphen_syntax(true);
+    config.set_double_hyphen_marker(false);
+    assert_eq!(result.to_args(&config).err(), Some(clargs::ParsingError::UnrepresentableArgument(String::from("-v"))));
+}
+
+#[test]
+fn to_args_test8() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_slash_syntax(true);
+    config.add_flag(String::from("v"));
+    config.add_subcommand(String::from("build"));
+
+    let args = ["clargs", "/usr/bin/x", "build", "z"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "/usr/bin/x", "build", "z"]);
+
+    let args = ["clargs", "/v", "/v/x", "build"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--v", "/v/x", "build"]);
+
+    config.set_double_hyphen_syntax(false);
+    config.set_single_hyphen_syntax(false);
+    let args = ["clargs", "-x", "build"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "-x", "build"]);
+
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::new());
+
+    let args = ["clargs", "--", "-"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--", "-"]);
+
+    let args = ["clargs", "-", "file"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "-", "file"]);
+}
+
+#[test]
+fn to_args_test9() {
+    let mut config = clargs::ParsingConfig::new();
+    config.set_unknown_passthrough(true);
+    config.add_passthrough_param(String::from("define"));
+    config.add_flag(String::from("verbose"));
+
+    let args = ["clargs", "--define", "x", "--color", "file", "--verbose"];
+    let canonical = round_trip(&args, &config);
+    assert_eq!(canonical, vec!["clargs", "--verbose", "--define", "x", "--color", "file"]);
+
+    let args = ["clargs", "file", "--define"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.to_args(&config).err(), Some(clargs::ParsingError::UnrepresentableOption(String::from("--define"))));
+
+    let args = ["clargs", "--color"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    config.set_unknown_passthrough(false);
+    assert_eq!(result.to_args(&config).err(), Some(clargs::ParsingError::UnrepresentableOption(String::from("--color"))));
+}
@@ -0,0 +1,133 @@
+extern crate clargs;
+
+#[test]
+fn usage_test1() {
+    let usage = "
+Usage: tool [-v] --out=FILE <input>...
+
+Options:
+  -v, --verbose     Print more output.
+  -o, --out=FILE    Write the result to FILE.
+";
+    let config = clargs::ParsingConfig::from_usage(usage).unwrap();
+
+    let args = ["tool", "-v", "-o", "result", "file1", "file2"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("out"), Some("result"));
+    assert_eq!(result.unnamed_params(), &vec![String::from("file1"), String::from("file2")]);
+
+    let args = ["tool", "file1"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredParameters(vec![String::from("out")]));
+}
+
+#[test]
+fn usage_test2() {
+    let usage = "
+Usage:
+  tool build [--release] [--jobs=N]
+  tool test [--filter=PATTERN]
+  tool [--level=N] (--quiet | --loud)
+";
+    let config = clargs::ParsingConfig::from_usage(usage).unwrap();
+
+    let args = ["tool", "--level", "3", "--quiet"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("level"), Some("3"));
+    assert!(result.has_flag("quiet"));
+
+    let args = ["tool", "build", "--release"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_subcommand(), Some("build"));
+    assert_eq!(result.subcommand_args(), &vec![String::from("build"), String::from("--release")]);
+
+    let args = ["tool", "--release"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("release")));
+}
+
+#[test]
+fn usage_test3() {
+    let usage = "
+Usage: tool (build | test) [-jN]
+
+Options:
+  -j N  Number of jobs.
+";
+    let config = clargs::ParsingConfig::from_usage(usage).unwrap();
+
+    let args = ["tool", "-j4"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::MissingRequiredSubcommand);
+
+    let args = ["tool", "-j4", "test"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.get_param("j"), Some("4"));
+    assert_eq!(result.get_subcommand(), Some("test"));
+}
+
+#[test]
+fn usage_test4() {
+    let result = clargs::ParsingConfig::from_usage("tool [-v]").err().unwrap();
+    assert_eq!((result.line(), result.column()), (1, 1));
+
+    let usage = "
+Usage: tool [-v (--out=FILE]
+";
+    let result = clargs::ParsingConfig::from_usage(usage).err().unwrap();
+    assert_eq!((result.line(), result.column(), result.message()), (2, 28, "unexpected ']'"));
+
+    let usage = "
+Usage: tool [-v]
+       tool --verbose
+       tool --verbose=LEVEL
+";
+    let result = clargs::ParsingConfig::from_usage(usage).err().unwrap();
+    assert_eq!((result.line(), result.column()), (4, 13));
+    assert_eq!(result.to_string(), "line 4, column 13: option 'verbose' is used both with and without an argument");
+}
+
+#[test]
+fn usage_test5() {
+    let usage = "
+Usage: tool [options] <file>
+
+Options:
+  -o FILE, --output FILE  Write to FILE.
+  --dry-run               Do nothing.
+  -x                      Unknown.
+  --=X                    Broken.
+";
+    let result = clargs::ParsingConfig::from_usage(usage).err().unwrap();
+    assert_eq!((result.line(), result.column()), (8, 3));
+}
+
+#[test]
+fn usage_test6() {
+    let usage = "
+Usage: tool [options] <file>
+
+Converts the file.
+ - see the manual for the formats
+ - the result is written next to the file
+
+Options:
+  -v, --verbose  Print more output.
+  --out=FILE     Write the result to FILE.
+
+Examples:
+  -v notes.txt
+";
+    let config = clargs::ParsingConfig::from_usage(usage).unwrap();
+
+    let args = ["tool", "-v", "--out", "x", "file"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.has_flag("verbose"));
+    assert_eq!(result.get_param("out"), Some("x"));
+
+    let args = ["tool", "--see", "file"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("see")));
+}
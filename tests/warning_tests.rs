@@ -0,0 +1,79 @@
+extern crate clargs;
+
+#[test]
+fn warning_test1() {
+    let config = clargs::ParsingConfig::builder()
+        .param("color")
+        .alias("colour").deprecated(Some("color"))
+        .flag("verbose")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--colour", "never", "--verbose"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.get_param("color"), Some("never"));
+    assert_eq!(result.warnings(), &[clargs::ParsingWarning::Deprecated(String::from("colour"), Some(String::from("color")))]);
+    assert_eq!(result.warnings()[0].to_string(), "option 'colour' is deprecated; use 'color'");
+
+    let args = ["clargs", "--color", "never", "--verbose"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.warnings(), &[]);
+}
+
+#[test]
+fn warning_test2() {
+    let mut config = clargs::ParsingConfig::new();
+    config.add_flag(String::from("q"));
+    config.add_alias(String::from("quiet"), String::from("q"));
+    config.set_deprecated(String::from("q"), true, None);
+
+    let args = ["clargs", "-q", "--quiet"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert!(result.has_flag("q"));
+    assert_eq!(result.warnings(), &[
+        clargs::ParsingWarning::Deprecated(String::from("q"), None),
+        clargs::ParsingWarning::Deprecated(String::from("quiet"), None),
+    ]);
+
+    config.set_deprecated(String::from("q"), false, None);
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert_eq!(result.warnings(), &[]);
+}
+
+#[test]
+fn warning_test3() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("verbose")
+        .param("output").alias("out-file")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--verb", "--out-f=file"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+
+    assert_eq!(result.warnings(), &[
+        clargs::ParsingWarning::Abbreviated(String::from("verb"), String::from("verbose")),
+        clargs::ParsingWarning::Abbreviated(String::from("out-f"), String::from("out-file")),
+    ]);
+    assert_eq!(result.warnings()[0].to_string(), "option 'verb' is an abbreviation; use 'verbose'");
+}
+
+#[test]
+fn warning_test4() {
+    let config = clargs::ParsingConfig::builder()
+        .flag("debug-internals").hidden()
+        .flag("delete")
+        .build()
+        .unwrap();
+
+    let args = ["clargs", "--de", "--debug-internals"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).unwrap();
+    assert!(result.has_flag("delete"));
+    assert!(result.has_flag("debug-internals"));
+
+    let args = ["clargs", "--debug"];
+    let result = clargs::parse(args.iter().map(|x| x.to_string()), &config).err().unwrap();
+    assert_eq!(result, clargs::ParsingError::UnrecognizedOption(String::from("debug")));
+}